@@ -29,7 +29,9 @@ async fn main() -> anyhow::Result<()> {
         ("designer", hina.designer.to_string()),
     ];
     let max = segments.iter().map(|(n, _)| n.len()).max().unwrap();
-    segments.map(|(name, details)| println!("{}{name}: {}", " ".repeat(max - name.len()), details));
+    segments.iter().for_each(|(name, details)| {
+        println!("{}{name}: {}", " ".repeat(max - name.len()), details)
+    });
 
     println!();
 
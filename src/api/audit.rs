@@ -0,0 +1,34 @@
+//! Functions for auditing the data against the structures of the wrapper, see the [`crate::audit`] module.
+
+use std::borrow::Borrow;
+
+use crate::{
+    audit::{AuditReport, AUDITED_ENDPOINTS},
+    source::DataSource,
+};
+
+use super::{internal::client_for, BlueArchiveClient, Language};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /**
+        Fetches all the [`AUDITED_ENDPOINTS`] and audits them, reporting the keys and values that the wrapper does not know of.
+
+        An endpoint that could not be fetched or is not valid JSON is recorded in [`AuditReport::failures`],
+        and the other endpoints are still audited.
+    */
+    pub async fn fetch_audit(&self) -> AuditReport {
+        let mut report = AuditReport::new(self.language());
+        for endpoint in AUDITED_ENDPOINTS {
+            report.audit(
+                endpoint,
+                self.source().fetch(endpoint, self.language()).await,
+            );
+        }
+        report
+    }
+}
+
+/// Fetches all the [`AUDITED_ENDPOINTS`] and audits them, reporting the keys and values that the wrapper does not know of.
+pub async fn fetch_audit(language: impl Borrow<Language>) -> AuditReport {
+    client_for(language).fetch_audit().await
+}
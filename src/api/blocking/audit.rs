@@ -0,0 +1,32 @@
+//! Functions for auditing the data against the structures of the wrapper, see the [`crate::audit`] module.
+
+use std::borrow::Borrow;
+
+use crate::{
+    audit::{AuditReport, AUDITED_ENDPOINTS},
+    source::BlockingDataSource,
+    Language,
+};
+
+use super::{client_for, BlueArchiveClient};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /**
+        Gets all the [`AUDITED_ENDPOINTS`] and audits them, reporting the keys and values that the wrapper does not know of.
+
+        An endpoint that could not be gotten or is not valid JSON is recorded in [`AuditReport::failures`],
+        and the other endpoints are still audited.
+    */
+    pub fn get_audit(&self) -> AuditReport {
+        let mut report = AuditReport::new(self.language());
+        for endpoint in AUDITED_ENDPOINTS {
+            report.audit(endpoint, self.source().get(endpoint, self.language()));
+        }
+        report
+    }
+}
+
+/// Gets all the [`AUDITED_ENDPOINTS`] and audits them, reporting the keys and values that the wrapper does not know of.
+pub fn get_audit(language: impl Borrow<Language>) -> AuditReport {
+    client_for(language).get_audit()
+}
@@ -0,0 +1,19 @@
+//! Functions primarily for geting [`Config`] data.
+
+use crate::{source::BlockingDataSource, types::Config, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets the **[`Config`]**, which holds the regions and what is currently happening in them.
+    ///
+    /// The configuration is the same for every [`Language`], so the language of the client does not matter.
+    pub fn get_config(&self) -> Result<Config, BlueArchiveError> {
+        self.get::<Config>(Endpoint::Config)
+    }
+}
+
+/// Gets the **[`Config`]**, which holds the regions and what is currently happening in them.
+pub fn get_config() -> Result<Config, BlueArchiveError> {
+    client_for(Language::English).get_config()
+}
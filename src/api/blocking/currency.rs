@@ -1,13 +1,38 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::currency::Currency;
 
 use crate::Language;
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client, Result,
-};
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Fetches all existing **[`Currency`]** currently in the database.
+    pub fn get_all_currencies(&self) -> Result<Vec<Currency>, BlueArchiveError> {
+        self.get::<Vec<Currency>>(Endpoint::Currency)
+    }
+
+    /// Gets all **[`Currencies`][`Currency`]** like [`Self::get_all_currencies`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_currencies_lenient(&self) -> Result<Decoded<Currency>, BlueArchiveError> {
+        self.get_lenient::<Currency>(Endpoint::Currency)
+    }
+
+    /// Fetches a specific **[`Currency`]** that matches with a provided **`name`** argument.
+    pub fn get_currency_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Currency>, BlueArchiveError> {
+        Ok(self
+            .get_all_currencies()?
+            .into_iter()
+            .find(|currency| currency.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+}
 
 /**
     Fetches all existing **[`Currency`]** currently in the database.
@@ -19,17 +44,23 @@ use super::{
         fn main() -> anyhow::Result<()> {
             println!(
                 "Total Currencies: [{}]",
-                blue_archive::blocking::get_all_currencies(Language::English).len()
+                blue_archive::blocking::get_all_currencies(Language::English)?.len()
             );
             Ok(())
         }
     ```
 */
-fn get_all_currencies(language: impl Borrow<Language>) -> Result<Vec<Currency>, BlueArchiveError> {
-    Ok(
-        get_response(&Endpoint::Currency, language.borrow(), &Client::new())?
-            .json::<Vec<Currency>>()?,
-    )
+pub fn get_all_currencies(
+    language: impl Borrow<Language>,
+) -> Result<Vec<Currency>, BlueArchiveError> {
+    client_for(language).get_all_currencies()
+}
+
+/// Gets all **[`Currencies`][`Currency`]** in a lenient way, see [`BlueArchiveClient::get_all_currencies_lenient`].
+pub fn get_all_currencies_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Currency>, BlueArchiveError> {
+    client_for(language).get_all_currencies_lenient()
 }
 
 /**
@@ -53,7 +84,5 @@ pub fn get_currency_by_name(
     name: impl AsRef<str>,
     language: impl Borrow<Language>,
 ) -> Result<Option<Currency>, BlueArchiveError> {
-    Ok(get_all_currencies(language)?
-        .into_iter()
-        .find(|currency| currency.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).get_currency_by_name(name)
 }
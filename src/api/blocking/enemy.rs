@@ -1,20 +1,49 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::enemy::Enemy;
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client, Result,
-};
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
 
 use crate::Language;
 
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Fetches all [`Enemy`]'s that are currently in the database.
+    pub fn get_all_enemies(&self) -> Result<Vec<Enemy>, BlueArchiveError> {
+        self.get::<Vec<Enemy>>(Endpoint::Enemies)
+    }
+
+    /// Gets all **[`Enemies`][`Enemy`]** like [`Self::get_all_enemies`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_enemies_lenient(&self) -> Result<Decoded<Enemy>, BlueArchiveError> {
+        self.get_lenient::<Enemy>(Endpoint::Enemies)
+    }
+
+    /// Fetches a specific **[`Enemy`]** that matches with a provided **`name`** argument.
+    pub fn get_enemy_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Enemy>, BlueArchiveError> {
+        Ok(self
+            .get_all_enemies()?
+            .into_iter()
+            .find(|enemy| enemy.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+}
+
 /// Fetches all [`Enemy`]'s that are currently in the database.
 pub fn get_all_enemies(language: impl Borrow<Language>) -> Result<Vec<Enemy>, BlueArchiveError> {
-    Ok(
-        get_response(&Endpoint::Enemies, language.borrow(), &Client::new())?
-            .json::<Vec<Enemy>>()?,
-    )
+    client_for(language).get_all_enemies()
+}
+
+/// Gets all **[`Enemies`][`Enemy`]** in a lenient way, see [`BlueArchiveClient::get_all_enemies_lenient`].
+pub fn get_all_enemies_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Enemy>, BlueArchiveError> {
+    client_for(language).get_all_enemies_lenient()
 }
 
 /**
@@ -26,7 +55,5 @@ pub fn get_enemy_by_name(
     language: impl Borrow<Language>,
     name: impl AsRef<str>,
 ) -> Result<Option<Enemy>, BlueArchiveError> {
-    Ok(get_all_enemies(language)?
-        .into_iter()
-        .find(|enemy| enemy.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).get_enemy_by_name(name)
 }
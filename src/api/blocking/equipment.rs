@@ -1,22 +1,63 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::equipment::{Equipment, EquipmentCategory};
 
 use crate::Language;
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client, Result,
-};
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /** Fetches all equipment in the database. */
+    pub fn get_all_equipment(&self) -> Result<Vec<Equipment>, BlueArchiveError> {
+        self.get::<Vec<Equipment>>(Endpoint::Equipment)
+    }
+
+    /// Gets all **[`Equipment`]** like [`Self::get_all_equipment`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_equipment_lenient(&self) -> Result<Decoded<Equipment>, BlueArchiveError> {
+        self.get_lenient::<Equipment>(Endpoint::Equipment)
+    }
+
+    /** Fetches all equipment that is equal to the given **`name`**. */
+    pub fn get_equipment_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Equipment>, BlueArchiveError> {
+        Ok(self
+            .get_all_equipment()?
+            .into_iter()
+            .find(|equipment| equipment.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+
+    /** Fetches all equipment that is equal to the given **[`EquipmentCategory`]**. */
+    pub fn get_equipment_by_category(
+        &self,
+        category: EquipmentCategory,
+    ) -> Result<Vec<Equipment>, BlueArchiveError> {
+        Ok(self
+            .get_all_equipment()?
+            .into_iter()
+            .filter(|equipment| equipment.category == category)
+            .collect::<Vec<_>>())
+    }
+}
 
 /** Fetches all equipment in the database. */
 pub fn get_all_equipment(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Equipment>, BlueArchiveError> {
-    Ok(
-        get_response(&Endpoint::Equipment, language.borrow(), &Client::new())?
-            .json::<Vec<Equipment>>()?,
-    )
+    client_for(language).get_all_equipment()
+}
+
+/// Gets all **[`Equipment`]** in a lenient way, see [`BlueArchiveClient::get_all_equipment_lenient`].
+pub fn get_all_equipment_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Equipment>, BlueArchiveError> {
+    client_for(language).get_all_equipment_lenient()
 }
 
 /** Fetches all equipment that is equal to the given **`name`**. */
@@ -24,9 +65,7 @@ pub fn get_equipment_by_name(
     language: impl Borrow<Language>,
     name: impl AsRef<str>,
 ) -> Result<Option<Equipment>, BlueArchiveError> {
-    Ok(get_all_equipment(language)?
-        .into_iter()
-        .find(|equipment| equipment.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).get_equipment_by_name(name)
 }
 
 /** Fetches all equipment that is equal to the given **[`EquipmentCategory`]**. */
@@ -34,8 +73,5 @@ pub fn get_equipment_by_category(
     language: impl Borrow<Language>,
     category: EquipmentCategory,
 ) -> Result<Vec<Equipment>, BlueArchiveError> {
-    Ok(get_all_equipment(language)?
-        .into_iter()
-        .filter(|equipment| equipment.category == category)
-        .collect::<Vec<_>>())
+    client_for(language).get_equipment_by_category(category)
 }
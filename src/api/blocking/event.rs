@@ -0,0 +1,34 @@
+//! Functions primarily for geting [`Event`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{source::BlockingDataSource, types::Event, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets all **[`Events`][`Event`]** from the data.
+    pub fn get_all_events(&self) -> Result<Vec<Event>, BlueArchiveError> {
+        self.get::<Vec<Event>>(Endpoint::Events)
+    }
+
+    /// Gets all **[`Events`][`Event`]** like [`Self::get_all_events`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_events_lenient(&self) -> Result<Decoded<Event>, BlueArchiveError> {
+        self.get_lenient::<Event>(Endpoint::Events)
+    }
+}
+
+/// Gets all **[`Events`][`Event`]** from the data.
+pub fn get_all_events(language: impl Borrow<Language>) -> Result<Vec<Event>, BlueArchiveError> {
+    client_for(language).get_all_events()
+}
+
+/// Gets all **[`Events`][`Event`]** in a lenient way, see [`BlueArchiveClient::get_all_events_lenient`].
+pub fn get_all_events_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Event>, BlueArchiveError> {
+    client_for(language).get_all_events_lenient()
+}
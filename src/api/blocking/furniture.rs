@@ -0,0 +1,44 @@
+//! Functions primarily for geting [`Furniture`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{source::BlockingDataSource, types::Furniture, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets all **[`Furniture`]** from the data.
+    pub fn get_all_furniture(&self) -> Result<Vec<Furniture>, BlueArchiveError> {
+        let mut furniture = self.get::<Vec<Furniture>>(Endpoint::Furniture)?;
+
+        furniture
+            .iter_mut()
+            .for_each(|piece| piece.set_image_uri(self.image_uri()));
+
+        Ok(furniture)
+    }
+
+    /// Gets all **[`Furniture`]** like [`Self::get_all_furniture`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_furniture_lenient(&self) -> Result<Decoded<Furniture>, BlueArchiveError> {
+        Ok(self
+            .get_lenient::<Furniture>(Endpoint::Furniture)?
+            .map_items(|piece| piece.set_image_uri(self.image_uri())))
+    }
+}
+
+/// Gets all **[`Furniture`]** from the data.
+pub fn get_all_furniture(
+    language: impl Borrow<Language>,
+) -> Result<Vec<Furniture>, BlueArchiveError> {
+    client_for(language).get_all_furniture()
+}
+
+/// Gets all **[`Furniture`]** in a lenient way, see [`BlueArchiveClient::get_all_furniture_lenient`].
+pub fn get_all_furniture_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Furniture>, BlueArchiveError> {
+    client_for(language).get_all_furniture_lenient()
+}
@@ -0,0 +1,56 @@
+//! Functions primarily for geting [`Item`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{
+    source::BlockingDataSource,
+    types::item::{Item, ItemIndex},
+    Language,
+};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets all **[`Items`][`Item`]** from the data.
+    pub fn get_all_items(&self) -> Result<Vec<Item>, BlueArchiveError> {
+        let mut items = self.get::<Vec<Item>>(Endpoint::Items)?;
+
+        items
+            .iter_mut()
+            .for_each(|item| item.set_image_uri(self.image_uri()));
+
+        Ok(items)
+    }
+
+    /// Gets all **[`Items`][`Item`]** like [`Self::get_all_items`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_items_lenient(&self) -> Result<Decoded<Item>, BlueArchiveError> {
+        Ok(self
+            .get_lenient::<Item>(Endpoint::Items)?
+            .map_items(|item| item.set_image_uri(self.image_uri())))
+    }
+
+    /// Gets all **[`Items`][`Item`]** and indexes them by their id in an **[`ItemIndex`]**.
+    pub fn get_item_index(&self) -> Result<ItemIndex, BlueArchiveError> {
+        Ok(ItemIndex::new(self.get_all_items()?))
+    }
+}
+
+/// Gets all **[`Items`][`Item`]** from the data.
+pub fn get_all_items(language: impl Borrow<Language>) -> Result<Vec<Item>, BlueArchiveError> {
+    client_for(language).get_all_items()
+}
+
+/// Gets all **[`Items`][`Item`]** in a lenient way, see [`BlueArchiveClient::get_all_items_lenient`].
+pub fn get_all_items_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Item>, BlueArchiveError> {
+    client_for(language).get_all_items_lenient()
+}
+
+/// Gets all **[`Items`][`Item`]** and indexes them by their id in an **[`ItemIndex`]**.
+pub fn get_item_index(language: impl Borrow<Language>) -> Result<ItemIndex, BlueArchiveError> {
+    client_for(language).get_item_index()
+}
@@ -0,0 +1,19 @@
+//! Functions primarily for geting [`Localization`] data.
+
+use std::borrow::Borrow;
+
+use crate::{source::BlockingDataSource, types::Localization, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets the **[`Localization`]**, which contains the localized names of stats, buffs, schools and more.
+    pub fn get_localization(&self) -> Result<Localization, BlueArchiveError> {
+        self.get::<Localization>(Endpoint::Localization)
+    }
+}
+
+/// Gets the **[`Localization`]**, which contains the localized names of stats, buffs, schools and more.
+pub fn get_localization(language: impl Borrow<Language>) -> Result<Localization, BlueArchiveError> {
+    client_for(language).get_localization()
+}
@@ -1,14 +1,46 @@
+pub mod audit;
+pub mod client;
+pub mod config;
 pub mod currency;
 pub mod enemy;
 pub mod equipment;
+pub mod event;
+pub mod furniture;
+pub mod item;
+pub mod localization;
 pub mod raid;
+pub mod stage;
 pub mod student;
 pub mod summon;
+pub mod voice;
 
-use anyhow::Result;
+use std::borrow::Borrow;
 
 use super::internal;
-use crate::BlueArchiveError;
+use crate::{BlueArchiveError, Language};
 use reqwest::blocking::Client;
 
-pub use self::{currency::*, enemy::*, equipment::*, raid::*, student::*, summon::*};
+pub use self::{
+    audit::*,
+    client::{BlueArchiveClient, BlueArchiveClientBuilder},
+    config::*,
+    currency::*,
+    enemy::*,
+    equipment::*,
+    event::*,
+    furniture::*,
+    item::*,
+    localization::*,
+    raid::*,
+    stage::*,
+    student::*,
+    summon::*,
+    voice::*,
+};
+
+/// Creates a blocking **[`BlueArchiveClient`]** for the module level functions, which only know of a [`Language`].
+pub(crate) fn client_for(language: impl Borrow<Language>) -> BlueArchiveClient {
+    BlueArchiveClient::builder()
+        .language(*language.borrow())
+        .build()
+}
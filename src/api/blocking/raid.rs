@@ -2,16 +2,22 @@
 
 use std::borrow::Borrow;
 
-use anyhow::Result;
-
 use crate::{types::RaidData, Language};
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client,
-};
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Fetches **[`RaidData`]**, which contains information related to raids in Blue Archive.
+    pub fn get_raid_data(&self) -> Result<RaidData, BlueArchiveError> {
+        let mut raid_data = self.get::<RaidData>(Endpoint::Raids)?;
+        raid_data.set_image_uri(self.image_uri());
+        Ok(raid_data)
+    }
+}
 
 /// Fetches **[`RaidData`]**, which contains information related to raids in Blue Archive.
 pub fn get_raid_data(language: impl Borrow<Language>) -> Result<RaidData, BlueArchiveError> {
-    Ok(get_response(&Endpoint::Raids, language.borrow(), &Client::new())?.json::<RaidData>()?)
+    client_for(language).get_raid_data()
 }
@@ -0,0 +1,19 @@
+//! Functions primarily for geting [`StageData`].
+
+use std::borrow::Borrow;
+
+use crate::{source::BlockingDataSource, types::StageData, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets **[`StageData`]**, which contains the stages of the campaign, bounties, commissions, scrimmages and events.
+    pub fn get_stage_data(&self) -> Result<StageData, BlueArchiveError> {
+        self.get::<StageData>(Endpoint::Stages)
+    }
+}
+
+/// Gets **[`StageData`]**, which contains the stages of the campaign, bounties, commissions, scrimmages and events.
+pub fn get_stage_data(language: impl Borrow<Language>) -> Result<StageData, BlueArchiveError> {
+    client_for(language).get_stage_data()
+}
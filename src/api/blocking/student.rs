@@ -2,30 +2,87 @@
 
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use rand::seq::IteratorRandom;
 
-use crate::{
-    filter::student::StudentFilterOptions,
-    types::{students::student::StudentImageData, Student},
-    Language,
-};
+use crate::{filter::student::StudentFilterOptions, types::Student, Language};
+
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets all students with extra data, which includes the images of the **[`Students`][`Student`]** among other things.
+    pub fn get_all_students(&self) -> Result<Vec<Student>, BlueArchiveError> {
+        let mut students = self.get::<Vec<Student>>(Endpoint::Students)?;
+
+        students
+            .iter_mut()
+            .for_each(|student| student.set_image_uri(self.image_uri()));
+
+        Ok(students)
+    }
+
+    /// Gets all **[`Students`][`Student`]** like [`Self::get_all_students`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_students_lenient(&self) -> Result<Decoded<Student>, BlueArchiveError> {
+        Ok(self
+            .get_lenient::<Student>(Endpoint::Students)?
+            .map_items(|student| student.set_image_uri(self.image_uri())))
+    }
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client, Result,
-};
+    /// Gets a **[`Student`]** by a `name` from a set of names, see [`get_student_by_name`] for the different methods.
+    pub fn get_student_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Student>, BlueArchiveError> {
+        let mut matched_student = None;
+
+        for student in self.get_all_students()? {
+            let lowercased = name.as_ref().to_lowercase();
+            let maybe_student = (lowercased == student.name.to_lowercase()
+                || lowercased == student.first_name.to_lowercase()
+                || lowercased == student.last_name.to_lowercase()
+                || lowercased == student.full_name_last().to_lowercase()
+                || lowercased == student.full_name_first().to_lowercase())
+            .then_some(student);
+            if let Some(student) = maybe_student {
+                matched_student = Some(student);
+                break;
+            }
+        }
+
+        Ok(matched_student)
+    }
+
+    /// Attempts to get a random **[`Student`]**.
+    pub fn get_random_student(&self) -> Result<Option<Student>, BlueArchiveError> {
+        Ok(self
+            .get_all_students()?
+            .into_iter()
+            .choose(&mut rand::thread_rng()))
+    }
+
+    /// Attempts to get a random amount of **[`Students`][`Student`]** depending on the specified **`amount`**.
+    pub fn get_random_students(&self, amount: usize) -> Result<Vec<Student>, BlueArchiveError> {
+        Ok(self
+            .get_all_students()?
+            .into_iter()
+            .choose_multiple(&mut rand::thread_rng(), amount))
+    }
+}
 
 /// Fetches all students with extra data, which includes the images of the **[`Students`][`Student`]** among other things.
 pub fn get_all_students(language: impl Borrow<Language>) -> Result<Vec<Student>, BlueArchiveError> {
-    let client = Client::new();
-    let mut students =
-        get_response(&Endpoint::Students, language.borrow(), &client)?.json::<Vec<Student>>()?;
-
-    students
-        .iter_mut()
-        .for_each(|student| student.image = StudentImageData::new(student));
+    client_for(language).get_all_students()
+}
 
-    Ok(students)
+/// Gets all **[`Students`][`Student`]** in a lenient way, see [`BlueArchiveClient::get_all_students_lenient`].
+pub fn get_all_students_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Student>, BlueArchiveError> {
+    client_for(language).get_all_students_lenient()
 }
 
 /**
@@ -56,23 +113,7 @@ pub fn get_student_by_name(
     name: impl AsRef<str>,
     language: impl Borrow<Language>,
 ) -> Result<Option<Student>, BlueArchiveError> {
-    let mut matched_student = None;
-
-    for student in get_all_students(language)? {
-        let lowercased = name.as_ref().to_lowercase();
-        let maybe_student = (lowercased == student.name.to_lowercase()
-            || lowercased == student.first_name.to_lowercase()
-            || lowercased == student.last_name.to_lowercase()
-            || lowercased == student.full_name_last().to_lowercase()
-            || lowercased == student.full_name_first().to_lowercase())
-        .then_some(student);
-        if let Some(student) = maybe_student {
-            matched_student = Some(student);
-            break;
-        }
-    }
-
-    Ok(matched_student)
+    client_for(language).get_student_by_name(name)
 }
 
 /// Attempts to get a random **[`Student`]**.
@@ -81,9 +122,7 @@ pub fn get_student_by_name(
 pub fn get_random_student(
     language: impl Borrow<Language>,
 ) -> Result<Option<Student>, BlueArchiveError> {
-    Ok(get_all_students(language)?
-        .into_iter()
-        .choose(&mut rand::thread_rng()))
+    client_for(language).get_random_student()
 }
 
 /// Attempts to get a random amount of **[`Students`][`Student`]** depending on the specified **`amount`**.
@@ -95,12 +134,10 @@ pub fn get_random_students(
     language: impl Borrow<Language>,
     amount: usize,
 ) -> Result<Vec<Student>, BlueArchiveError> {
-    Ok(get_all_students(language)?
-        .into_iter()
-        .choose_multiple(&mut rand::thread_rng(), amount))
+    client_for(language).get_random_students(amount)
 }
 
 /// Returns **[`StudentFilterOptions`]** to be used with the provided **[`Vec<Student>`]** for filtering.
-pub fn filter(students: &Vec<Student>) -> StudentFilterOptions {
+pub fn filter(students: &Vec<Student>) -> StudentFilterOptions<'_> {
     StudentFilterOptions::new(students)
 }
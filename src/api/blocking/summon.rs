@@ -2,17 +2,43 @@
 
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::{types::Summon, Language};
 
-use super::{
-    internal::{get_response, Endpoint},
-    BlueArchiveError, Client,
-};
+use crate::source::BlockingDataSource;
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Fetches all **[`Summons`][`Summon`]** from the data.
+    pub fn get_all_summons(&self) -> Result<Vec<Summon>, BlueArchiveError> {
+        let mut summons = self.get::<Vec<Summon>>(Endpoint::Summons)?;
+
+        summons
+            .iter_mut()
+            .for_each(|summon| summon.set_image_uri(self.image_uri()));
+
+        Ok(summons)
+    }
+
+    /// Gets all **[`Summons`][`Summon`]** like [`Self::get_all_summons`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub fn get_all_summons_lenient(&self) -> Result<Decoded<Summon>, BlueArchiveError> {
+        Ok(self
+            .get_lenient::<Summon>(Endpoint::Summons)?
+            .map_items(|summon| summon.set_image_uri(self.image_uri())))
+    }
+}
 
 /// Fetches all **[`Summons`][`Summon`]** from the data.
 pub fn get_all_summons(language: impl Borrow<Language>) -> Result<Vec<Summon>, BlueArchiveError> {
-    Ok(
-        get_response(&Endpoint::Summons, language.borrow(), &Client::new())?
-            .json::<Vec<Summon>>()?,
-    )
+    client_for(language).get_all_summons()
+}
+
+/// Gets all **[`Summons`][`Summon`]** in a lenient way, see [`BlueArchiveClient::get_all_summons_lenient`].
+pub fn get_all_summons_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Summon>, BlueArchiveError> {
+    client_for(language).get_all_summons_lenient()
 }
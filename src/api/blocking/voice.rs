@@ -0,0 +1,21 @@
+//! Functions primarily for geting [`VoiceData`] data.
+
+use std::borrow::Borrow;
+
+use crate::{source::BlockingDataSource, types::VoiceData, Language};
+
+use super::{client_for, internal::Endpoint, BlueArchiveClient, BlueArchiveError};
+
+impl<S: BlockingDataSource> BlueArchiveClient<S> {
+    /// Gets the **[`VoiceData`]**, which contains the voice lines of every **[`Student`][`crate::types::Student`]**.
+    pub fn get_all_voice_lines(&self) -> Result<VoiceData, BlueArchiveError> {
+        let mut voice_data = self.get::<VoiceData>(Endpoint::Voice)?;
+        voice_data.set_voice_uri(self.voice_uri());
+        Ok(voice_data)
+    }
+}
+
+/// Gets the **[`VoiceData`]**, which contains the voice lines of every **[`Student`][`crate::types::Student`]**.
+pub fn get_all_voice_lines(language: impl Borrow<Language>) -> Result<VoiceData, BlueArchiveError> {
+    client_for(language).get_all_voice_lines()
+}
@@ -0,0 +1,202 @@
+//! Contains the **[`BlueArchiveClient`]**, a reusable client that the rest of the api is built upon.
+
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+
+use super::{BlueArchiveError, Client, Language};
+use crate::{
+    errors::Decoded,
+    serialization,
+    source::{http::trim_uri, DataSource, DirectorySource, Endpoint, HttpSource, RetryPolicy},
+    IMAGE_DATA_URI, VOICE_DATA_URI,
+};
+
+/**
+    A reusable client that owns a **[`DataSource`]**, the root of the images, and a default **[`Language`]**.
+
+    By default, the source is a **[`HttpSource`]**, which owns a **[`reqwest::Client`]**.
+    Creating one client and reusing it allows for connection pooling between requests,
+    which the crate level functions (such as [`crate::fetch_all_students`]) do not benefit from.
+
+    Any other **[`DataSource`]** can be used through [`BlueArchiveClientBuilder::source`],
+    such as a **[`DirectorySource`]** to read a local ``SchaleDB`` `data/` directory without any network involved.
+
+    # Examples
+    ```
+    use anyhow::Result;
+    use blue_archive::{BlueArchiveClient, Language};
+
+    #[tokio::main]
+    async fn main() -> Result<()> {
+        let client = BlueArchiveClient::builder()
+            .language(Language::Japanese)
+            .build();
+        let students = client.fetch_all_students().await?;
+        let enemies = client.fetch_all_enemies().await?;
+        println!("students: {}, enemies: {}", students.len(), enemies.len());
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Clone)]
+pub struct BlueArchiveClient<S = HttpSource> {
+    source: S,
+    image_uri: String,
+    voice_uri: String,
+    language: Language,
+}
+
+impl BlueArchiveClient {
+    /// Creates a new **[`BlueArchiveClient`]** with the default configuration, using **[`Language::English`]**.
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Returns a **[`BlueArchiveClientBuilder`]** to configure a **[`BlueArchiveClient`]** with.
+    pub fn builder() -> BlueArchiveClientBuilder {
+        BlueArchiveClientBuilder::default()
+    }
+}
+
+impl<S> BlueArchiveClient<S> {
+    /// The **[`DataSource`]** that data is fetched from.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// The default **[`Language`]** that data is fetched in.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The root of the images that urls are built from, e.g. [`IMAGE_DATA_URI`].
+    pub fn image_uri(&self) -> &str {
+        &self.image_uri
+    }
+
+    /// The root of the voice lines that audio urls are built from, e.g. [`VOICE_DATA_URI`].
+    pub fn voice_uri(&self) -> &str {
+        &self.voice_uri
+    }
+
+    /// Returns a copy of this client that fetches data in the given **[`Language`]**, while still sharing the same source.
+    pub fn with_language(&self, language: Language) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            language,
+            ..self.clone()
+        }
+    }
+}
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches and deserializes the data of an **[`Endpoint`]** in the default **[`Language`]**.
+    pub(crate) async fn fetch<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+    ) -> Result<T, BlueArchiveError> {
+        let bytes = self.source.fetch(endpoint, self.language).await?;
+        serialization::from_slice(&bytes, endpoint, self.language)
+    }
+
+    /// Fetches the data of an **[`Endpoint`]** in the default **[`Language`]**, deserializing each of its elements on its own.
+    pub(crate) async fn fetch_lenient<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+    ) -> Result<Decoded<T>, BlueArchiveError> {
+        let bytes = self.source.fetch(endpoint, self.language).await?;
+        serialization::from_slice_lenient(&bytes, endpoint, self.language)
+    }
+}
+
+impl Default for BlueArchiveClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A builder for the **[`BlueArchiveClient`]**, obtained through [`BlueArchiveClient::builder`].
+#[derive(Debug, Default)]
+pub struct BlueArchiveClientBuilder<S = HttpSource> {
+    source: S,
+    image_uri: Option<String>,
+    voice_uri: Option<String>,
+    language: Option<Language>,
+}
+
+impl BlueArchiveClientBuilder {
+    /// Uses an already configured **[`reqwest::Client`]** instead of creating a new one.
+    pub fn client(mut self, client: Client) -> Self {
+        self.source.set_client(client);
+        self
+    }
+
+    /// Sets the root of the data that is fetched, defaults to [`crate::DATA_URI`].
+    ///
+    /// This can point to a mirror, a pinned commit or a local server, e.g. `http://localhost:8080/data`.
+    pub fn data_uri(mut self, data_uri: impl Into<String>) -> Self {
+        self.source = self.source.with_data_uri(data_uri);
+        self
+    }
+
+    /// Sets the **[`RetryPolicy`]** of failed requests, defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.source = self.source.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Reads the data from a local directory instead of fetching it, such as the `data/` directory of a checked-out ``SchaleDB``.
+    ///
+    /// This is a shorthand for using a **[`DirectorySource`]** as the source.
+    pub fn data_directory(
+        self,
+        data_directory: impl Into<PathBuf>,
+    ) -> BlueArchiveClientBuilder<DirectorySource> {
+        self.source(DirectorySource::new(data_directory))
+    }
+}
+
+impl<S> BlueArchiveClientBuilder<S> {
+    /// Sets the **[`DataSource`]** that data is fetched from, defaults to a **[`HttpSource`]**.
+    pub fn source<T: DataSource>(self, source: T) -> BlueArchiveClientBuilder<T> {
+        BlueArchiveClientBuilder {
+            source,
+            image_uri: self.image_uri,
+            voice_uri: self.voice_uri,
+            language: self.language,
+        }
+    }
+
+    /// Sets the root of the images that urls are built from, defaults to [`IMAGE_DATA_URI`].
+    ///
+    /// Every url built from fetched data, such as [`crate::types::students::student::Gear::icon_url`], is relative to this root.
+    pub fn image_uri(mut self, image_uri: impl Into<String>) -> Self {
+        self.image_uri = Some(trim_uri(image_uri.into()));
+        self
+    }
+
+    /// Sets the root of the voice lines that audio urls are built from, defaults to [`VOICE_DATA_URI`].
+    pub fn voice_uri(mut self, voice_uri: impl Into<String>) -> Self {
+        self.voice_uri = Some(trim_uri(voice_uri.into()));
+        self
+    }
+
+    /// Sets the default **[`Language`]**, defaults to [`Language::English`].
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Builds the **[`BlueArchiveClient`]**.
+    pub fn build(self) -> BlueArchiveClient<S> {
+        BlueArchiveClient {
+            source: self.source,
+            image_uri: self.image_uri.unwrap_or_else(|| IMAGE_DATA_URI.to_string()),
+            voice_uri: self.voice_uri.unwrap_or_else(|| VOICE_DATA_URI.to_string()),
+            language: self.language.unwrap_or(Language::English),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Functions primarily for fetching [`Config`] data.
+
+use crate::{source::DataSource, types::Config};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches the **[`Config`]**, which holds the regions and what is currently happening in them.
+    ///
+    /// The configuration is the same for every [`Language`], so the language of the client does not matter.
+    pub async fn fetch_config(&self) -> Result<Config, BlueArchiveError> {
+        self.fetch::<Config>(Endpoint::Config).await
+    }
+}
+
+/// Fetches the **[`Config`]**, which holds the regions and what is currently happening in them.
+pub async fn fetch_config() -> Result<Config, BlueArchiveError> {
+    client_for(Language::English).fetch_config().await
+}
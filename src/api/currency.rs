@@ -1,12 +1,43 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::currency::Currency;
 
+use crate::source::DataSource;
+
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Client, Language, Result,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all existing **[`Currency`]** currently in the database.
+    pub async fn fetch_all_currencies(&self) -> Result<Vec<Currency>, BlueArchiveError> {
+        self.fetch::<Vec<Currency>>(Endpoint::Currency).await
+    }
+
+    /// Fetches all **[`Currencies`][`Currency`]** like [`Self::fetch_all_currencies`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_currencies_lenient(
+        &self,
+    ) -> Result<Decoded<Currency>, BlueArchiveError> {
+        self.fetch_lenient::<Currency>(Endpoint::Currency).await
+    }
+
+    /// Fetches a specific **[`Currency`]** that matches with a provided **`name`** argument.
+    pub async fn fetch_currency_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Currency>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_currencies()
+            .await?
+            .into_iter()
+            .find(|currency| currency.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+}
+
 /**
     Fetches all existing **[`Currency`]** currently in the database.
 
@@ -29,12 +60,14 @@ use super::{
 pub async fn fetch_all_currencies(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Currency>, BlueArchiveError> {
-    Ok(
-        fetch_response(&Endpoint::Currency, language.borrow(), &Client::new())
-            .await?
-            .json::<Vec<Currency>>()
-            .await?,
-    )
+    client_for(language).fetch_all_currencies().await
+}
+
+/// Fetches all **[`Currencies`][`Currency`]** in a lenient way, see [`BlueArchiveClient::fetch_all_currencies_lenient`].
+pub async fn fetch_all_currencies_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Currency>, BlueArchiveError> {
+    client_for(language).fetch_all_currencies_lenient().await
 }
 
 /**
@@ -60,8 +93,5 @@ pub async fn fetch_currency_by_name(
     name: impl AsRef<str>,
     language: impl Borrow<Language>,
 ) -> Result<Option<Currency>, BlueArchiveError> {
-    Ok(fetch_all_currencies(language)
-        .await?
-        .into_iter()
-        .find(|currency| currency.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).fetch_currency_by_name(name).await
 }
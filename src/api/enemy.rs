@@ -1,22 +1,53 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::enemy::Enemy;
 
+use crate::source::DataSource;
+
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Client, Language, Result,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all [`Enemy`]'s that are currently in the database.
+    pub async fn fetch_all_enemies(&self) -> Result<Vec<Enemy>, BlueArchiveError> {
+        self.fetch::<Vec<Enemy>>(Endpoint::Enemies).await
+    }
+
+    /// Fetches all **[`Enemies`][`Enemy`]** like [`Self::fetch_all_enemies`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_enemies_lenient(&self) -> Result<Decoded<Enemy>, BlueArchiveError> {
+        self.fetch_lenient::<Enemy>(Endpoint::Enemies).await
+    }
+
+    /// Fetches a specific **[`Enemy`]** that matches with a provided **`name`** argument.
+    pub async fn fetch_enemy_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Enemy>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_enemies()
+            .await?
+            .into_iter()
+            .find(|enemy| enemy.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+}
+
 /// Fetches all [`Enemy`]'s that are currently in the database.
 pub async fn fetch_all_enemies(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Enemy>, BlueArchiveError> {
-    Ok(
-        fetch_response(&Endpoint::Enemies, language.borrow(), &Client::new())
-            .await?
-            .json::<Vec<Enemy>>()
-            .await?,
-    )
+    client_for(language).fetch_all_enemies().await
+}
+
+/// Fetches all **[`Enemies`][`Enemy`]** in a lenient way, see [`BlueArchiveClient::fetch_all_enemies_lenient`].
+pub async fn fetch_all_enemies_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Enemy>, BlueArchiveError> {
+    client_for(language).fetch_all_enemies_lenient().await
 }
 
 /**
@@ -28,8 +59,5 @@ pub async fn fetch_enemy_by_name(
     language: impl Borrow<Language>,
     name: impl AsRef<str>,
 ) -> Result<Option<Enemy>, BlueArchiveError> {
-    Ok(fetch_all_enemies(language)
-        .await?
-        .into_iter()
-        .find(|enemy| enemy.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).fetch_enemy_by_name(name).await
 }
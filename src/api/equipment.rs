@@ -1,22 +1,68 @@
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
 use crate::types::equipment::{Equipment, EquipmentCategory};
 
+use crate::source::DataSource;
+
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Client, Language, Result,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /** Fetches all equipment in the database. */
+    pub async fn fetch_all_equipment(&self) -> Result<Vec<Equipment>, BlueArchiveError> {
+        self.fetch::<Vec<Equipment>>(Endpoint::Equipment).await
+    }
+
+    /// Fetches all **[`Equipment`]** like [`Self::fetch_all_equipment`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_equipment_lenient(
+        &self,
+    ) -> Result<Decoded<Equipment>, BlueArchiveError> {
+        self.fetch_lenient::<Equipment>(Endpoint::Equipment).await
+    }
+
+    /** Fetches all equipment that is equal to the given **`name`**. */
+    pub async fn fetch_equipment_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Equipment>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_equipment()
+            .await?
+            .into_iter()
+            .find(|equipment| equipment.name.to_lowercase() == name.as_ref().to_lowercase()))
+    }
+
+    /** Fetches all equipment that is equal to the given **[`EquipmentCategory`]**. */
+    pub async fn fetch_equipment_by_category(
+        &self,
+        category: EquipmentCategory,
+    ) -> Result<Vec<Equipment>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_equipment()
+            .await?
+            .into_iter()
+            .filter(|equipment| equipment.category == category)
+            .collect::<Vec<_>>())
+    }
+}
+
 /** Fetches all equipment in the database. */
 pub async fn fetch_all_equipment(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Equipment>, BlueArchiveError> {
-    Ok(
-        fetch_response(&Endpoint::Equipment, language.borrow(), &Client::new())
-            .await?
-            .json::<Vec<Equipment>>()
-            .await?,
-    )
+    client_for(language).fetch_all_equipment().await
+}
+
+/// Fetches all **[`Equipment`]** in a lenient way, see [`BlueArchiveClient::fetch_all_equipment_lenient`].
+pub async fn fetch_all_equipment_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Equipment>, BlueArchiveError> {
+    client_for(language).fetch_all_equipment_lenient().await
 }
 
 /** Fetches all equipment that is equal to the given **`name`**. */
@@ -24,10 +70,7 @@ pub async fn fetch_equipment_by_name(
     language: impl Borrow<Language>,
     name: impl AsRef<str>,
 ) -> Result<Option<Equipment>, BlueArchiveError> {
-    Ok(fetch_all_equipment(language)
-        .await?
-        .into_iter()
-        .find(|equipment| equipment.name.to_lowercase() == name.as_ref().to_lowercase()))
+    client_for(language).fetch_equipment_by_name(name).await
 }
 
 /** Fetches all equipment that is equal to the given **[`EquipmentCategory`]**. */
@@ -35,9 +78,7 @@ pub async fn fetch_equipment_by_category(
     language: impl Borrow<Language>,
     category: EquipmentCategory,
 ) -> Result<Vec<Equipment>, BlueArchiveError> {
-    Ok(fetch_all_equipment(language)
-        .await?
-        .into_iter()
-        .filter(|equipment| equipment.category == category)
-        .collect::<Vec<_>>())
+    client_for(language)
+        .fetch_equipment_by_category(category)
+        .await
 }
@@ -0,0 +1,39 @@
+//! Functions primarily for fetching [`Event`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{source::DataSource, types::Event};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all **[`Events`][`Event`]** from the data.
+    pub async fn fetch_all_events(&self) -> Result<Vec<Event>, BlueArchiveError> {
+        self.fetch::<Vec<Event>>(Endpoint::Events).await
+    }
+
+    /// Fetches all **[`Events`][`Event`]** like [`Self::fetch_all_events`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_events_lenient(&self) -> Result<Decoded<Event>, BlueArchiveError> {
+        self.fetch_lenient::<Event>(Endpoint::Events).await
+    }
+}
+
+/// Fetches all **[`Events`][`Event`]** from the data.
+pub async fn fetch_all_events(
+    language: impl Borrow<Language>,
+) -> Result<Vec<Event>, BlueArchiveError> {
+    client_for(language).fetch_all_events().await
+}
+
+/// Fetches all **[`Events`][`Event`]** in a lenient way, see [`BlueArchiveClient::fetch_all_events_lenient`].
+pub async fn fetch_all_events_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Event>, BlueArchiveError> {
+    client_for(language).fetch_all_events_lenient().await
+}
@@ -0,0 +1,50 @@
+//! Functions primarily for fetching [`Furniture`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{source::DataSource, types::Furniture};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all **[`Furniture`]** from the data.
+    pub async fn fetch_all_furniture(&self) -> Result<Vec<Furniture>, BlueArchiveError> {
+        let mut furniture = self.fetch::<Vec<Furniture>>(Endpoint::Furniture).await?;
+
+        furniture
+            .iter_mut()
+            .for_each(|piece| piece.set_image_uri(self.image_uri()));
+
+        Ok(furniture)
+    }
+
+    /// Fetches all **[`Furniture`]** like [`Self::fetch_all_furniture`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_furniture_lenient(
+        &self,
+    ) -> Result<Decoded<Furniture>, BlueArchiveError> {
+        Ok(self
+            .fetch_lenient::<Furniture>(Endpoint::Furniture)
+            .await?
+            .map_items(|piece| piece.set_image_uri(self.image_uri())))
+    }
+}
+
+/// Fetches all **[`Furniture`]** from the data.
+pub async fn fetch_all_furniture(
+    language: impl Borrow<Language>,
+) -> Result<Vec<Furniture>, BlueArchiveError> {
+    client_for(language).fetch_all_furniture().await
+}
+
+/// Fetches all **[`Furniture`]** in a lenient way, see [`BlueArchiveClient::fetch_all_furniture_lenient`].
+pub async fn fetch_all_furniture_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Furniture>, BlueArchiveError> {
+    client_for(language).fetch_all_furniture_lenient().await
+}
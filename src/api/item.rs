@@ -0,0 +1,63 @@
+//! Functions primarily for fetching [`Item`] data.
+
+use std::borrow::Borrow;
+
+use crate::errors::Decoded;
+
+use crate::{
+    source::DataSource,
+    types::item::{Item, ItemIndex},
+};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all **[`Items`][`Item`]** from the data.
+    pub async fn fetch_all_items(&self) -> Result<Vec<Item>, BlueArchiveError> {
+        let mut items = self.fetch::<Vec<Item>>(Endpoint::Items).await?;
+
+        items
+            .iter_mut()
+            .for_each(|item| item.set_image_uri(self.image_uri()));
+
+        Ok(items)
+    }
+
+    /// Fetches all **[`Items`][`Item`]** like [`Self::fetch_all_items`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_items_lenient(&self) -> Result<Decoded<Item>, BlueArchiveError> {
+        Ok(self
+            .fetch_lenient::<Item>(Endpoint::Items)
+            .await?
+            .map_items(|item| item.set_image_uri(self.image_uri())))
+    }
+
+    /// Fetches all **[`Items`][`Item`]** and indexes them by their id in an **[`ItemIndex`]**.
+    pub async fn fetch_item_index(&self) -> Result<ItemIndex, BlueArchiveError> {
+        Ok(ItemIndex::new(self.fetch_all_items().await?))
+    }
+}
+
+/// Fetches all **[`Items`][`Item`]** from the data.
+pub async fn fetch_all_items(
+    language: impl Borrow<Language>,
+) -> Result<Vec<Item>, BlueArchiveError> {
+    client_for(language).fetch_all_items().await
+}
+
+/// Fetches all **[`Items`][`Item`]** in a lenient way, see [`BlueArchiveClient::fetch_all_items_lenient`].
+pub async fn fetch_all_items_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Item>, BlueArchiveError> {
+    client_for(language).fetch_all_items_lenient().await
+}
+
+/// Fetches all **[`Items`][`Item`]** and indexes them by their id in an **[`ItemIndex`]**.
+pub async fn fetch_item_index(
+    language: impl Borrow<Language>,
+) -> Result<ItemIndex, BlueArchiveError> {
+    client_for(language).fetch_item_index().await
+}
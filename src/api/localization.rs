@@ -0,0 +1,24 @@
+//! Functions primarily for fetching [`Localization`] data.
+
+use std::borrow::Borrow;
+
+use crate::{source::DataSource, types::Localization};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches the **[`Localization`]**, which contains the localized names of stats, buffs, schools and more.
+    pub async fn fetch_localization(&self) -> Result<Localization, BlueArchiveError> {
+        self.fetch::<Localization>(Endpoint::Localization).await
+    }
+}
+
+/// Fetches the **[`Localization`]**, which contains the localized names of stats, buffs, schools and more.
+pub async fn fetch_localization(
+    language: impl Borrow<Language>,
+) -> Result<Localization, BlueArchiveError> {
+    client_for(language).fetch_localization().await
+}
@@ -4,71 +4,45 @@
 #[cfg(feature = "blocking")]
 pub mod blocking;
 
+pub mod audit;
+pub mod client;
+pub mod config;
 pub mod currency;
 pub mod enemy;
 pub mod equipment;
+pub mod event;
+pub mod furniture;
+pub mod item;
+pub mod localization;
 pub mod raid;
+pub mod stage;
 pub mod student;
 pub mod summon;
+pub mod voice;
 
 use crate::enums::Language;
 use crate::filter::student::StudentFilterOptions;
 use crate::types::{RaidData, Student, Summon};
-use crate::{BlueArchiveError, DATA_URI};
+use crate::BlueArchiveError;
 
 use rand::seq::IteratorRandom;
 use reqwest::Client;
 pub use reqwest::{Request, Response, StatusCode};
 
-use anyhow::Result;
-use strum_macros::Display;
+pub use client::{BlueArchiveClient, BlueArchiveClientBuilder};
 
 /// Internal functions to work with the data easier.
 pub(crate) mod internal {
-    use super::{BlueArchiveError, Client, Display, Language, Response, Result, DATA_URI};
+    use std::borrow::Borrow;
 
-    /// Contains the endpoints for the data, they mainly just represent the path of what data is obtained.
-    #[derive(Debug, Display)]
-    pub enum Endpoint {
-        _Localization,
-        _Voice,
-        _Furniture,
-        _Items,
-        Enemies,
-        Equipment,
-        Currency,
-        Raids,
-        Students,
-        Summons,
-    }
+    pub(crate) use crate::source::Endpoint;
 
-    /// Fetches a response using a given endpoint.
-    pub(crate) async fn fetch_response(
-        endpoint: &Endpoint,
-        language: &Language,
-        client: &Client,
-    ) -> Result<Response, BlueArchiveError> {
-        let url = format!(
-            "{}/{}/{}.json",
-            DATA_URI,
-            language.id(),
-            endpoint.to_string().to_lowercase()
-        );
-        Ok(client.get(url).send().await?.error_for_status()?)
-    }
+    use super::{BlueArchiveClient, Language};
 
-    #[cfg(feature = "blocking")]
-    pub(crate) fn get_response(
-        endpoint: &Endpoint,
-        language: &Language,
-        client: &reqwest::blocking::Client,
-    ) -> Result<reqwest::blocking::Response, BlueArchiveError> {
-        let url = format!(
-            "{}/{}/{}.json",
-            DATA_URI,
-            language.id(),
-            endpoint.to_string().to_lowercase()
-        );
-        Ok(client.get(url).send()?.error_for_status()?)
+    /// Creates a **[`BlueArchiveClient`]** for the crate level functions, which only know of a [`Language`].
+    pub(crate) fn client_for(language: impl Borrow<Language>) -> BlueArchiveClient {
+        BlueArchiveClient::builder()
+            .language(*language.borrow())
+            .build()
     }
 }
@@ -2,22 +2,25 @@
 
 use std::borrow::Borrow;
 
-use anyhow::Result;
-use reqwest::Client;
+use crate::source::DataSource;
 
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Language, RaidData,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language, RaidData,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches **[`RaidData`]**, which contains information related to raids in Blue Archive.
+    pub async fn fetch_raid_data(&self) -> Result<RaidData, BlueArchiveError> {
+        let mut raid_data = self.fetch::<RaidData>(Endpoint::Raids).await?;
+        raid_data.set_image_uri(self.image_uri());
+        Ok(raid_data)
+    }
+}
+
 /// Fetches **[`RaidData`]**, which contains information related to raids in Blue Archive.
 pub async fn fetch_raid_data(
     language: impl Borrow<Language>,
 ) -> Result<RaidData, BlueArchiveError> {
-    Ok(
-        fetch_response(&Endpoint::Raids, language.borrow(), &Client::new())
-            .await?
-            .json::<RaidData>()
-            .await?,
-    )
+    client_for(language).fetch_raid_data().await
 }
@@ -0,0 +1,24 @@
+//! Functions primarily for fetching [`StageData`].
+
+use std::borrow::Borrow;
+
+use crate::{source::DataSource, types::StageData};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches **[`StageData`]**, which contains the stages of the campaign, bounties, commissions, scrimmages and events.
+    pub async fn fetch_stage_data(&self) -> Result<StageData, BlueArchiveError> {
+        self.fetch::<StageData>(Endpoint::Stages).await
+    }
+}
+
+/// Fetches **[`StageData`]**, which contains the stages of the campaign, bounties, commissions, scrimmages and events.
+pub async fn fetch_stage_data(
+    language: impl Borrow<Language>,
+) -> Result<StageData, BlueArchiveError> {
+    client_for(language).fetch_stage_data().await
+}
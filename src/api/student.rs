@@ -2,28 +2,94 @@
 
 use std::borrow::Borrow;
 
-use crate::types::students::student::StudentImageData;
+use crate::errors::Decoded;
+
+use crate::source::DataSource;
 
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Client, IteratorRandom, Language, Result, Student, StudentFilterOptions,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, IteratorRandom, Language, Student, StudentFilterOptions,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all students with extra data, which includes the images of the **[`Students`][`Student`]** among other things.
+    pub async fn fetch_all_students(&self) -> Result<Vec<Student>, BlueArchiveError> {
+        let mut students = self.fetch::<Vec<Student>>(Endpoint::Students).await?;
+
+        students
+            .iter_mut()
+            .for_each(|student| student.set_image_uri(self.image_uri()));
+
+        Ok(students)
+    }
+
+    /// Fetches all **[`Students`][`Student`]** like [`Self::fetch_all_students`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_students_lenient(&self) -> Result<Decoded<Student>, BlueArchiveError> {
+        Ok(self
+            .fetch_lenient::<Student>(Endpoint::Students)
+            .await?
+            .map_items(|student| student.set_image_uri(self.image_uri())))
+    }
+
+    /// Fetches a **[`Student`]** by a `name` from a set of names, see [`fetch_student_by_name`] for the different methods.
+    pub async fn fetch_student_by_name(
+        &self,
+        name: impl AsRef<str>,
+    ) -> Result<Option<Student>, BlueArchiveError> {
+        let mut matched_student = None;
+
+        for student in self.fetch_all_students().await? {
+            let lowercased = name.as_ref().to_lowercase();
+            let maybe_student = (lowercased == student.name.to_lowercase()
+                || lowercased == student.first_name.to_lowercase()
+                || lowercased == student.last_name.to_lowercase()
+                || lowercased == student.full_name_last().to_lowercase()
+                || lowercased == student.full_name_first().to_lowercase())
+            .then_some(student);
+            if let Some(student) = maybe_student {
+                matched_student = Some(student);
+                break;
+            }
+        }
+
+        Ok(matched_student)
+    }
+
+    /// Attempts to fetch a random **[`Student`]**.
+    pub async fn fetch_random_student(&self) -> Result<Option<Student>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_students()
+            .await?
+            .into_iter()
+            .choose(&mut rand::thread_rng()))
+    }
+
+    /// Attempts to fetch a random amount of **[`Students`][`Student`]** depending on the specified **`amount`**.
+    pub async fn fetch_random_students(
+        &self,
+        amount: usize,
+    ) -> Result<Vec<Student>, BlueArchiveError> {
+        Ok(self
+            .fetch_all_students()
+            .await?
+            .into_iter()
+            .choose_multiple(&mut rand::thread_rng(), amount))
+    }
+}
+
 /// Fetches all students with extra data, which includes the images of the **[`Students`][`Student`]** among other things.
 pub async fn fetch_all_students(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Student>, BlueArchiveError> {
-    let client = Client::new();
-    let mut students = fetch_response(&Endpoint::Students, language.borrow(), &client)
-        .await?
-        .json::<Vec<Student>>()
-        .await?;
-
-    students
-        .iter_mut()
-        .for_each(|student| student.image = StudentImageData::new(student));
+    client_for(language).fetch_all_students().await
+}
 
-    Ok(students)
+/// Fetches all **[`Students`][`Student`]** in a lenient way, see [`BlueArchiveClient::fetch_all_students_lenient`].
+pub async fn fetch_all_students_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Student>, BlueArchiveError> {
+    client_for(language).fetch_all_students_lenient().await
 }
 
 /**
@@ -55,23 +121,7 @@ pub async fn fetch_student_by_name(
     name: impl AsRef<str>,
     language: impl Borrow<Language>,
 ) -> Result<Option<Student>, BlueArchiveError> {
-    let mut matched_student = None;
-
-    for student in fetch_all_students(language).await? {
-        let lowercased = name.as_ref().to_lowercase();
-        let maybe_student = (lowercased == student.name.to_lowercase()
-            || lowercased == student.first_name.to_lowercase()
-            || lowercased == student.last_name.to_lowercase()
-            || lowercased == student.full_name_last().to_lowercase()
-            || lowercased == student.full_name_first().to_lowercase())
-        .then_some(student);
-        if let Some(student) = maybe_student {
-            matched_student = Some(student);
-            break;
-        }
-    }
-
-    Ok(matched_student)
+    client_for(language).fetch_student_by_name(name).await
 }
 
 /// Attempts to fetch a random **[`Student`]**.
@@ -80,10 +130,7 @@ pub async fn fetch_student_by_name(
 pub async fn fetch_random_student(
     language: impl Borrow<Language>,
 ) -> Result<Option<Student>, BlueArchiveError> {
-    Ok(fetch_all_students(language)
-        .await?
-        .into_iter()
-        .choose(&mut rand::thread_rng()))
+    client_for(language).fetch_random_student().await
 }
 
 /// Attempts to fetch a random amount of **[`Students`][`Student`]** depending on the specified **`amount`**.
@@ -95,13 +142,10 @@ pub async fn fetch_random_students(
     language: impl Borrow<Language>,
     amount: usize,
 ) -> Result<Vec<Student>, BlueArchiveError> {
-    Ok(fetch_all_students(language)
-        .await?
-        .into_iter()
-        .choose_multiple(&mut rand::thread_rng(), amount))
+    client_for(language).fetch_random_students(amount).await
 }
 
 /// Returns **[`StudentFilterOptions`]** to be used with the provided **[`Vec<Student>`]** for filtering.
-pub fn filter(students: &Vec<Student>) -> StudentFilterOptions {
+pub fn filter(students: &Vec<Student>) -> StudentFilterOptions<'_> {
     StudentFilterOptions::new(students)
 }
@@ -2,19 +2,47 @@
 
 use std::borrow::Borrow;
 
+use crate::errors::Decoded;
+
+use crate::source::DataSource;
+
 use super::{
-    internal::{fetch_response, Endpoint},
-    BlueArchiveError, Client, Language, Result, Summon,
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language, Summon,
 };
 
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches all **[`Summons`][`Summon`]** from the data.
+    pub async fn fetch_all_summons(&self) -> Result<Vec<Summon>, BlueArchiveError> {
+        let mut summons = self.fetch::<Vec<Summon>>(Endpoint::Summons).await?;
+
+        summons
+            .iter_mut()
+            .for_each(|summon| summon.set_image_uri(self.image_uri()));
+
+        Ok(summons)
+    }
+
+    /// Fetches all **[`Summons`][`Summon`]** like [`Self::fetch_all_summons`], though each one is deserialized on its own,
+    /// dropping the ones that fail and reporting them in the **[`Decoded`]** result.
+    pub async fn fetch_all_summons_lenient(&self) -> Result<Decoded<Summon>, BlueArchiveError> {
+        Ok(self
+            .fetch_lenient::<Summon>(Endpoint::Summons)
+            .await?
+            .map_items(|summon| summon.set_image_uri(self.image_uri())))
+    }
+}
+
 /// Fetches all **[`Summons`][`Summon`]** from the data.
 pub async fn fetch_all_summons(
     language: impl Borrow<Language>,
 ) -> Result<Vec<Summon>, BlueArchiveError> {
-    Ok(
-        fetch_response(&Endpoint::Summons, language.borrow(), &Client::new())
-            .await?
-            .json::<Vec<Summon>>()
-            .await?,
-    )
+    client_for(language).fetch_all_summons().await
+}
+
+/// Fetches all **[`Summons`][`Summon`]** in a lenient way, see [`BlueArchiveClient::fetch_all_summons_lenient`].
+pub async fn fetch_all_summons_lenient(
+    language: impl Borrow<Language>,
+) -> Result<Decoded<Summon>, BlueArchiveError> {
+    client_for(language).fetch_all_summons_lenient().await
 }
@@ -0,0 +1,26 @@
+//! Functions primarily for fetching [`VoiceData`] data.
+
+use std::borrow::Borrow;
+
+use crate::{source::DataSource, types::VoiceData};
+
+use super::{
+    internal::{client_for, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+impl<S: DataSource> BlueArchiveClient<S> {
+    /// Fetches the **[`VoiceData`]**, which contains the voice lines of every **[`Student`][`crate::types::Student`]**.
+    pub async fn fetch_all_voice_lines(&self) -> Result<VoiceData, BlueArchiveError> {
+        let mut voice_data = self.fetch::<VoiceData>(Endpoint::Voice).await?;
+        voice_data.set_voice_uri(self.voice_uri());
+        Ok(voice_data)
+    }
+}
+
+/// Fetches the **[`VoiceData`]**, which contains the voice lines of every **[`Student`][`crate::types::Student`]**.
+pub async fn fetch_all_voice_lines(
+    language: impl Borrow<Language>,
+) -> Result<VoiceData, BlueArchiveError> {
+    client_for(language).fetch_all_voice_lines().await
+}
@@ -0,0 +1,797 @@
+//! Detects schema drift between the data and the structures of the wrapper.
+//!
+//! Much of the data is deserialized leniently, such as an [`Effect::Unknown`] for an effect that is not known,
+//! a [`School::Unknown`] for a school that is not known, or keys that are not represented at all.
+//! The **[`AuditReport`]** lists all of them for each entity, which can be used to learn of additions to the data early.
+//!
+//! Keys that are inside of untagged or internally tagged `enum`s, such as the fields of an **[`Effect`]**, cannot be detected.
+
+use std::{str::FromStr, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    serialization,
+    source::Endpoint,
+    types::{
+        currency::Currency,
+        enemy::Enemy,
+        equipment::Equipment,
+        events::Event,
+        furniture::{Furniture, FurnitureCategory, FurnitureSubCategory},
+        item::{Item, ItemCategory, ItemSubCategory},
+        raids::{DungeonType, Raid, RaidDifficulty, RaidSeason, TimeAttack, WorldRaid},
+        stages::{RewardType, Stage, StageData},
+        students::student::LevelUpType,
+        summons::Skill as SummonSkill,
+        Config, CriticalCheck, Effect, Localization, RaidData, SkillKind, Student, Summon,
+        VoiceData,
+    },
+    Armor, BlueArchiveError, BulletType, Club, Language, Position, School, Squad, TacticalRole,
+    Terrain, WeaponType,
+};
+
+/// The endpoints that can be audited, as they are represented by the wrapper, which are all of them.
+pub const AUDITED_ENDPOINTS: [Endpoint; 13] = [
+    Endpoint::Config,
+    Endpoint::Localization,
+    Endpoint::Voice,
+    Endpoint::Students,
+    Endpoint::Enemies,
+    Endpoint::Equipment,
+    Endpoint::Events,
+    Endpoint::Furniture,
+    Endpoint::Items,
+    Endpoint::Currency,
+    Endpoint::Raids,
+    Endpoint::Stages,
+    Endpoint::Summons,
+];
+
+/// A finding of an audit, in which the `path` is where it is in the data, such as `[12].Skills[3].Effects[0].Type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// A key that is present in the data, though is ignored by the structure it is deserialized into.
+    IgnoredKey { path: String },
+    /// A value that is deserialized into an unknown variant, such as [`Effect::Unknown`], where `kind` is the name of the type.
+    UnknownValue {
+        path: String,
+        kind: &'static str,
+        value: String,
+    },
+    /// The entity could not be deserialized at all.
+    DecodeFailed { path: String, error: String },
+}
+
+impl Finding {
+    /// Where the finding is in the data.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::IgnoredKey { path }
+            | Self::UnknownValue { path, .. }
+            | Self::DecodeFailed { path, .. } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IgnoredKey { path } => write!(f, "ignored key `{path}`"),
+            Self::UnknownValue { path, kind, value } => {
+                write!(f, "unknown {kind} `{value}` at `{path}`")
+            }
+            Self::DecodeFailed { path, error } => write!(f, "failed to decode `{path}`: {error}"),
+        }
+    }
+}
+
+/// The findings of a single entity in the data, such as a student.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityAudit {
+    /// Where the entity is in the data, such as `[12]`, or empty if the findings are of the data itself.
+    pub path: String,
+    /// The `Id` of the entity, if it has one.
+    pub id: Option<u32>,
+    /// The `Name` of the entity, if it has one.
+    pub name: Option<String>,
+    /// What was found in the entity.
+    pub findings: Vec<Finding>,
+}
+
+/// The findings of the data of an **[`Endpoint`]**, with only the entities that have any findings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointAudit {
+    pub endpoint: Endpoint,
+    pub entities: Vec<EntityAudit>,
+}
+
+impl EndpointAudit {
+    /// Whether nothing was found.
+    pub fn is_clean(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+/// An **[`Endpoint`]** that could not be audited, such as when it could not be fetched or is not valid JSON.
+#[derive(Debug, Clone)]
+pub struct AuditFailure {
+    pub endpoint: Endpoint,
+    pub error: Arc<BlueArchiveError>,
+}
+
+/// The findings of an audit of all the [`AUDITED_ENDPOINTS`] in a **[`Language`]**.
+#[derive(Debug, Clone)]
+pub struct AuditReport {
+    pub language: Language,
+    pub endpoints: Vec<EndpointAudit>,
+    /// The endpoints that could not be audited, which are left out of [`AuditReport::endpoints`].
+    pub failures: Vec<AuditFailure>,
+}
+
+impl AuditReport {
+    pub(crate) fn new(language: Language) -> Self {
+        Self {
+            language,
+            endpoints: vec![],
+            failures: vec![],
+        }
+    }
+
+    /// Audits the data of an endpoint, or records why it could not be audited.
+    pub(crate) fn audit(&mut self, endpoint: Endpoint, data: Result<Arc<[u8]>, BlueArchiveError>) {
+        match data.and_then(|data| audit_endpoint(endpoint, self.language, &data)) {
+            Ok(audit) => self.endpoints.push(audit),
+            Err(error) => self.failures.push(AuditFailure {
+                endpoint,
+                error: Arc::new(error),
+            }),
+        }
+    }
+
+    /// Whether every endpoint could be audited, and nothing was found in any of them.
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty() && self.endpoints.iter().all(EndpointAudit::is_clean)
+    }
+
+    /// Every finding of the audit, alongside the **[`Endpoint`]** and the entity it was found in.
+    pub fn findings(&self) -> impl Iterator<Item = (Endpoint, &EntityAudit, &Finding)> {
+        self.endpoints.iter().flat_map(|audit| {
+            audit.entities.iter().flat_map(move |entity| {
+                entity
+                    .findings
+                    .iter()
+                    .map(move |finding| (audit.endpoint, entity, finding))
+            })
+        })
+    }
+}
+
+/**
+    Audits the raw data of an **[`Endpoint`]**, such as data that was loaded from a file.
+
+    Fails if the data is not valid JSON.
+
+    # Examples
+    ```
+    use blue_archive::{audit::audit_endpoint, source::Endpoint, Language};
+
+    let data = br#"[{ "Id": 1, "Category": "Currency", "Rarity": "R", "Icon": "", "Name": "Credits", "Desc": "", "Tags": [] }]"#;
+    let audit = audit_endpoint(Endpoint::Currency, Language::English, data)?;
+    assert_eq!(audit.entities[0].findings[0].path(), "[0].Tags");
+    # Ok::<(), blue_archive::BlueArchiveError>(())
+    ```
+*/
+pub fn audit_endpoint(
+    endpoint: Endpoint,
+    language: Language,
+    data: &[u8],
+) -> Result<EndpointAudit, BlueArchiveError> {
+    let document = serialization::from_slice::<Value>(data, endpoint, language)?;
+    let findings = match endpoint {
+        Endpoint::Config => audit_value::<Config>(&document, ""),
+        Endpoint::Localization => audit_value::<Localization>(&document, ""),
+        Endpoint::Voice => audit_value::<VoiceData>(&document, ""),
+        Endpoint::Students => audit_elements::<Student>(&document),
+        Endpoint::Enemies => audit_elements::<Enemy>(&document),
+        Endpoint::Equipment => audit_elements::<Equipment>(&document),
+        Endpoint::Events => audit_elements::<Event>(&document),
+        Endpoint::Furniture => audit_elements::<Furniture>(&document),
+        Endpoint::Items => audit_elements::<Item>(&document),
+        Endpoint::Currency => audit_elements::<Currency>(&document),
+        Endpoint::Summons => audit_elements::<Summon>(&document),
+        Endpoint::Raids => audit_value::<RaidData>(&document, ""),
+        Endpoint::Stages => audit_value::<StageData>(&document, ""),
+    };
+    Ok(EndpointAudit {
+        endpoint,
+        entities: group_by_entity(&document, findings),
+    })
+}
+
+/// A structure of the data that can report the values it did not know.
+pub(crate) trait Audit: DeserializeOwned {
+    /// Records the path relative to itself, and the name of the type, of every value that was not known.
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>);
+}
+
+fn audit_elements<T: Audit>(document: &Value) -> Vec<Finding> {
+    match document {
+        Value::Array(elements) => elements
+            .iter()
+            .enumerate()
+            .flat_map(|(index, element)| audit_value::<T>(element, &format!("[{index}]")))
+            .collect(),
+        _ => audit_value::<Vec<T>>(document, ""),
+    }
+}
+
+/// Deserializes a value, reporting the keys that were ignored and the values that were not known.
+fn audit_value<T: Audit>(value: &Value, prefix: &str) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut ignored = |path: serde_ignored::Path| {
+        findings.push(Finding::IgnoredKey {
+            path: join(prefix, &format_path(&path)),
+        })
+    };
+    let result = serde_path_to_error::deserialize::<_, T>(serde_ignored::Deserializer::new(
+        value,
+        &mut ignored,
+    ));
+
+    match result {
+        Ok(item) => {
+            let mut found = vec![];
+            item.unknown_values(&mut found);
+            findings.extend(found.into_iter().map(|(path, kind)| {
+                let value = match lookup(value, &path) {
+                    Some(Value::String(string)) => string.clone(),
+                    Some(value) => value.to_string(),
+                    None => "null".to_string(),
+                };
+                Finding::UnknownValue {
+                    path: join(prefix, &path),
+                    kind,
+                    value,
+                }
+            }));
+        }
+        Err(error) => findings.push(Finding::DecodeFailed {
+            path: join(prefix, &error.path().to_string()),
+            error: error.into_inner().to_string(),
+        }),
+    }
+    findings
+}
+
+/// Groups findings by the entity they are in, which is the first element along their path.
+fn group_by_entity(document: &Value, findings: Vec<Finding>) -> Vec<EntityAudit> {
+    let mut entities: Vec<EntityAudit> = vec![];
+    for finding in findings {
+        let path = finding
+            .path()
+            .find(']')
+            .map(|end| finding.path()[..=end].to_string())
+            .unwrap_or_default();
+        match entities.iter_mut().find(|entity| entity.path == path) {
+            Some(entity) => entity.findings.push(finding),
+            None => {
+                let entity = lookup(document, &path);
+                entities.push(EntityAudit {
+                    id: entity
+                        .and_then(|entity| entity.get("Id"))
+                        .and_then(Value::as_u64)
+                        .and_then(|id| u32::try_from(id).ok()),
+                    name: entity
+                        .and_then(|entity| entity.get("Name"))
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                    path,
+                    findings: vec![finding],
+                })
+            }
+        }
+    }
+    entities
+}
+
+/// Formats a path the same way as a [`BlueArchiveError::Decode`], such as `Skills[3].Effects`.
+fn format_path(path: &serde_ignored::Path) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => format!("{}[{index}]", format_path(parent)),
+        serde_ignored::Path::Map { parent, key } => join(&format_path(parent), key),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => format_path(parent),
+    }
+}
+
+fn join(prefix: &str, path: &str) -> String {
+    match (prefix.is_empty(), path.is_empty() || path == ".") {
+        (_, true) => prefix.to_string(),
+        (true, false) => path.to_string(),
+        (false, false) if path.starts_with('[') => format!("{prefix}{path}"),
+        (false, false) => format!("{prefix}.{path}"),
+    }
+}
+
+/// Finds the value at a path such as `Skills[3].Effects`.
+fn lookup<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    let mut value = value;
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        let (key, indices) = part.split_once('[').unwrap_or((part, ""));
+        if !key.is_empty() {
+            value = value.get(key)?;
+        }
+        for index in indices.split(['[', ']']).filter(|index| !index.is_empty()) {
+            value = value.get(index.parse::<usize>().ok()?)?;
+        }
+    }
+    Some(value)
+}
+
+fn check(found: &mut Vec<(String, &'static str)>, unknown: bool, path: String, kind: &'static str) {
+    if unknown {
+        found.push((path, kind));
+    }
+}
+
+fn audit_effects(effects: &[Effect], path: &str, found: &mut Vec<(String, &'static str)>) {
+    for (index, effect) in effects.iter().enumerate() {
+        let critical_check = match effect {
+            Effect::DMGSingle { critical_check, .. }
+            | Effect::DMGMulti { critical_check, .. }
+            | Effect::FormChange { critical_check, .. } => critical_check.as_ref(),
+            Effect::DMGEcho { critical_check, .. }
+            | Effect::DMGZone { critical_check, .. }
+            | Effect::DMGEchoWithScaling { critical_check, .. } => Some(critical_check),
+            _ => None,
+        };
+        check(
+            found,
+            *effect == Effect::Unknown,
+            format!("{path}[{index}].Type"),
+            "Effect",
+        );
+        check(
+            found,
+            critical_check == Some(&CriticalCheck::Unknown),
+            format!("{path}[{index}].CriticalCheck"),
+            "CriticalCheck",
+        );
+    }
+}
+
+impl<T: Audit> Audit for Vec<T> {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        for (index, item) in self.iter().enumerate() {
+            let mut item_found = vec![];
+            item.unknown_values(&mut item_found);
+            found.extend(
+                item_found
+                    .into_iter()
+                    .map(|(path, kind)| (join(&format!("[{index}]"), &path), kind)),
+            );
+        }
+    }
+}
+
+impl Audit for Student {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        let fields = [
+            (
+                matches!(self.school(), School::Unknown(_)),
+                "School",
+                "School",
+            ),
+            (matches!(self.club(), Club::Unknown(_)), "Club", "Club"),
+            (
+                matches!(self.squad(), Squad::Unknown(_)),
+                "SquadType",
+                "Squad",
+            ),
+            (
+                matches!(self.tactical_role(), TacticalRole::Unknown(_)),
+                "TacticRole",
+                "TacticalRole",
+            ),
+            (
+                matches!(self.position(), Position::Unknown(_)),
+                "Position",
+                "Position",
+            ),
+            (
+                matches!(self.bullet_type(), BulletType::Unknown(_)),
+                "BulletType",
+                "BulletType",
+            ),
+            (
+                matches!(self.armor(), Armor::Unknown(_)),
+                "ArmorType",
+                "Armor",
+            ),
+            (
+                matches!(self.weapon_type(), WeaponType::Unknown(_)),
+                "WeaponType",
+                "WeaponType",
+            ),
+        ];
+        for (unknown, path, kind) in fields {
+            check(found, unknown, path.to_string(), kind);
+        }
+
+        for (index, skill) in self.skills.iter().enumerate() {
+            check(
+                found,
+                skill.kind == SkillKind::Unknown,
+                format!("Skills[{index}].SkillType"),
+                "SkillKind",
+            );
+            audit_effects(&skill.effects, &format!("Skills[{index}].Effects"), found);
+        }
+
+        check(
+            found,
+            self.weapon.stat_level_up_type == LevelUpType::Unknown,
+            "Weapon.StatLevelUpType".to_string(),
+            "LevelUpType",
+        );
+    }
+}
+
+impl Audit for Enemy {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.squad(), Squad::Unknown(_)),
+            "SquadType".to_string(),
+            "Squad",
+        );
+        check(
+            found,
+            matches!(self.bullet_type(), BulletType::Unknown(_)),
+            "BulletType".to_string(),
+            "BulletType",
+        );
+        check(
+            found,
+            matches!(self.armor(), Armor::Unknown(_)),
+            "ArmorType".to_string(),
+            "Armor",
+        );
+        check(
+            found,
+            matches!(self.weapon_type(), WeaponType::Unknown(_)),
+            "WeaponType".to_string(),
+            "WeaponType",
+        );
+    }
+}
+
+impl Audit for Equipment {
+    fn unknown_values(&self, _: &mut Vec<(String, &'static str)>) {}
+}
+
+impl Audit for Event {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        for (index, item) in self.shop.iter().enumerate() {
+            check(
+                found,
+                matches!(item.goods.reward_type(), RewardType::Unknown(_)),
+                format!("Shop[{index}].Goods.Type"),
+                "RewardType",
+            );
+        }
+        for (index, point_reward) in self.point_rewards.iter().enumerate() {
+            for (reward_index, reward) in point_reward.rewards.iter().enumerate() {
+                check(
+                    found,
+                    matches!(reward.reward_type(), RewardType::Unknown(_)),
+                    format!("PointRewards[{index}].Rewards[{reward_index}].Type"),
+                    "RewardType",
+                );
+            }
+        }
+    }
+}
+
+impl Audit for Furniture {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.category(), FurnitureCategory::Unknown(_)),
+            "Category".to_string(),
+            "FurnitureCategory",
+        );
+        check(
+            found,
+            matches!(self.sub_category(), FurnitureSubCategory::Unknown(_)),
+            "SubCategory".to_string(),
+            "FurnitureSubCategory",
+        );
+    }
+}
+
+impl Audit for Item {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.category(), ItemCategory::Unknown(_)),
+            "Category".to_string(),
+            "ItemCategory",
+        );
+        check(
+            found,
+            matches!(self.sub_category(), Some(ItemSubCategory::Unknown(_))),
+            "SubCategory".to_string(),
+            "ItemSubCategory",
+        );
+    }
+}
+
+impl Audit for Currency {
+    fn unknown_values(&self, _: &mut Vec<(String, &'static str)>) {}
+}
+
+impl Audit for Localization {
+    fn unknown_values(&self, _: &mut Vec<(String, &'static str)>) {}
+}
+
+impl Audit for VoiceData {
+    fn unknown_values(&self, _: &mut Vec<(String, &'static str)>) {}
+}
+
+impl Audit for Config {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        for (index, region) in self.regions.iter().enumerate() {
+            check(
+                found,
+                region.region().is_none(),
+                format!("Regions[{index}].Name"),
+                "Region",
+            );
+            for (raid_index, raid) in region.current_raid.iter().enumerate() {
+                check(
+                    found,
+                    matches!(raid.terrain(), Some(Terrain::Unknown(_))),
+                    format!("Regions[{index}].CurrentRaid[{raid_index}].terrain"),
+                    "Terrain",
+                );
+            }
+        }
+    }
+}
+
+impl Audit for Summon {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.bullet_type(), BulletType::Unknown(_)),
+            "BulletType".to_string(),
+            "BulletType",
+        );
+        check(
+            found,
+            matches!(self.armor(), Armor::Unknown(_)),
+            "ArmorType".to_string(),
+            "Armor",
+        );
+        check(
+            found,
+            matches!(self.weapon_type(), WeaponType::Unknown(_)),
+            "WeaponType".to_string(),
+            "WeaponType",
+        );
+        for (index, skill) in self.skills.iter().enumerate() {
+            check(
+                found,
+                *skill == SummonSkill::Unknown,
+                format!("Skills[{index}].SkillType"),
+                "SkillType",
+            );
+            let effects = match skill {
+                SummonSkill::AutoAttack { effects, .. } => effects.as_deref(),
+                SummonSkill::Normal(skill) => skill.effects.as_deref(),
+                SummonSkill::Passive(skill) => skill.effects.as_deref(),
+                SummonSkill::Unknown => None,
+            };
+            audit_effects(
+                effects.unwrap_or_default(),
+                &format!("Skills[{index}].Effects"),
+                found,
+            );
+        }
+    }
+}
+
+impl Audit for Raid {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.armor(), Armor::Unknown(_)),
+            "ArmorType".to_string(),
+            "Armor",
+        );
+        check(
+            found,
+            BulletType::from_str(&self.bullet_type).is_err(),
+            "BulletType".to_string(),
+            "BulletType",
+        );
+        check(
+            found,
+            self.bullet_type_insane
+                .as_ref()
+                .is_some_and(|bullet_type| BulletType::from_str(bullet_type).is_err()),
+            "BulletTypeInsane".to_string(),
+            "BulletType",
+        );
+        for (index, skill) in self.skills.iter().enumerate() {
+            audit_effects(
+                skill.effects(),
+                &format!("RaidSkill[{index}].Effects"),
+                found,
+            );
+        }
+    }
+}
+
+impl Audit for RaidData {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        audit_nested(&self.raids, "Raid", found);
+        audit_nested(&self.world_raid, "WorldRaid", found);
+        audit_nested(&self.time_attack, "TimeAttack", found);
+        for (index, seasons) in self.raid_seasons.iter().enumerate() {
+            audit_nested(
+                &seasons.seasons,
+                &format!("RaidSeasons[{index}].Seasons"),
+                found,
+            );
+            audit_nested(
+                &seasons.eliminate_seasons,
+                &format!("RaidSeasons[{index}].EliminateSeasons"),
+                found,
+            );
+        }
+    }
+}
+
+/// Records the unknown values of a nested structure, with their paths relative to the `key` it is at.
+fn audit_nested<T: Audit>(nested: &T, key: &str, found: &mut Vec<(String, &'static str)>) {
+    let mut nested_found = vec![];
+    nested.unknown_values(&mut nested_found);
+    found.extend(
+        nested_found
+            .into_iter()
+            .map(|(path, kind)| (format!("{key}{path}"), kind)),
+    );
+}
+
+impl Audit for WorldRaid {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        for (index, terrain) in self.terrains().iter().enumerate() {
+            check(
+                found,
+                matches!(terrain, Terrain::Unknown(_)),
+                format!("Terrain[{index}]"),
+                "Terrain",
+            );
+        }
+        for (index, phase) in self.phases().iter().enumerate() {
+            let path = match self.has_phases() {
+                true => format!("Phases[{index}]."),
+                false => String::new(),
+            };
+            check(
+                found,
+                matches!(phase.armor(), Armor::Unknown(_)),
+                format!("{path}ArmorType"),
+                "Armor",
+            );
+            check(
+                found,
+                matches!(phase.bullet_type(), BulletType::Unknown(_)),
+                format!("{path}BulletType"),
+                "BulletType",
+            );
+        }
+        for (index, skill) in self.skills.iter().enumerate() {
+            audit_effects(
+                skill.effects(),
+                &format!("RaidSkill[{index}].Effects"),
+                found,
+            );
+        }
+    }
+}
+
+impl Audit for RaidSeason {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.terrain(), Terrain::Unknown(_)),
+            "Terrain".to_string(),
+            "Terrain",
+        );
+        for (index, difficulty) in self.opened_difficulties().iter().enumerate() {
+            check(
+                found,
+                matches!(difficulty, RaidDifficulty::Unknown(_)),
+                format!("OpenDifficulty[{index}]"),
+                "RaidDifficulty",
+            );
+        }
+        check(
+            found,
+            matches!(self.armor(), Some(Armor::Unknown(_))),
+            "ArmorType".to_string(),
+            "Armor",
+        );
+    }
+}
+
+impl Audit for TimeAttack {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.dungeon_type(), DungeonType::Unknown(_)),
+            "DungeonType".to_string(),
+            "DungeonType",
+        );
+        check(
+            found,
+            matches!(self.terrain(), Terrain::Unknown(_)),
+            "Terrain".to_string(),
+            "Terrain",
+        );
+        check(
+            found,
+            matches!(self.bullet_type(), BulletType::Unknown(_)),
+            "BulletType".to_string(),
+            "BulletType",
+        );
+        check(
+            found,
+            matches!(self.armor(), Armor::Unknown(_)),
+            "ArmorType".to_string(),
+            "Armor",
+        );
+    }
+}
+
+impl Audit for StageData {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        audit_nested(&self.campaign, "Campaign", found);
+        audit_nested(&self.week_dungeon, "WeekDungeon", found);
+        audit_nested(&self.school_dungeon, "SchoolDungeon", found);
+        audit_nested(&self.event, "Event", found);
+    }
+}
+
+impl Audit for Stage {
+    fn unknown_values(&self, found: &mut Vec<(String, &'static str)>) {
+        check(
+            found,
+            matches!(self.terrain(), Some(Terrain::Unknown(_))),
+            "Terrain".to_string(),
+            "Terrain",
+        );
+        let tables = [
+            ("Default", &self.rewards.default),
+            ("FirstClear", &self.rewards.first_clear),
+            ("ThreeStar", &self.rewards.three_star),
+        ];
+        for (key, rewards) in tables {
+            for (index, reward) in rewards.iter().enumerate() {
+                check(
+                    found,
+                    matches!(reward.reward_type(), Some(RewardType::Unknown(_))),
+                    format!("Rewards.{key}[{index}].Type"),
+                    "RewardType",
+                );
+            }
+        }
+    }
+}
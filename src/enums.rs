@@ -1,9 +1,10 @@
 //! Contains useful public enums to be used when working with the API wrapper.
 
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Languages that **``SchaleDB``** supports.
-#[derive(Debug, Display, Clone, Copy)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum Language {
     English,
     Chinese,
@@ -28,6 +29,43 @@ impl Language {
     }
 }
 
+/// The regions that Blue Archive is served in, each of which has its own releases and schedule.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum Region {
+    Japan,
+    Global,
+    China,
+}
+
+impl Region {
+    /// The index of the region in data that is split by region, such as the seasons of raids.
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            Self::Japan => 0,
+            Self::Global => 1,
+            Self::China => 2,
+        }
+    }
+
+    /// The time of day in UTC that the server of the region resets its daily content at, which is 04:00 in its local time.
+    pub fn daily_reset_time(&self) -> NaiveTime {
+        let hour = match self {
+            Self::Japan | Self::Global => 19,
+            Self::China => 20,
+        };
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap_or_default()
+    }
+
+    /// The next daily reset of the server of the region after the given instant.
+    pub fn next_daily_reset(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let reset = at.date_naive().and_time(self.daily_reset_time()).and_utc();
+        match reset > at {
+            true => reset,
+            false => reset + Duration::days(1),
+        }
+    }
+}
+
 /**
     **This is a `enum` that contains the current Blue Archive schools represented in the data.**
 
@@ -196,6 +234,26 @@ pub enum Position {
     Unknown(String),
 }
 
+/**
+    **This is a `enum` that contains the current Blue Archive terrains represented in the data.**
+
+    This is the current list of terrains represented in the data.
+    * **Street**
+    * **Outdoor**
+    * **Indoor**
+
+    In the case that a terrain in the data is not present on the wrapper,
+    a [`Terrain::Unknown(String)`] is returned to represent the unknown terrain with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum Terrain {
+    Street,
+    Outdoor,
+    Indoor,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
 /**
     **This is a `enum` that contains the current Blue Archive bullet types represented in the data.**
 
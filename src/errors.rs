@@ -1,14 +1,171 @@
 //! Error handling for the api wrapper.
 
+use std::path::{Path, PathBuf};
+
+use reqwest::StatusCode;
 use thiserror::Error;
 
-/// Contains underlying information on why an error has happened with the wrapper.
-/// For now, it internally holds a **[`reqwest::Error`]** `struct`.
+use crate::{source::Endpoint, Language};
+
+/**
+    Contains underlying information on why an error has happened with the wrapper.
+
+    Every variant carries the **[`Endpoint`]** and **[`Language`]** of the data that failed,
+    which can also be obtained through [`BlueArchiveError::endpoint`] and [`BlueArchiveError::language`].
+*/
 #[derive(Debug, Error)]
-pub struct BlueArchiveError(#[from] pub reqwest::Error);
+pub enum BlueArchiveError {
+    /// The data could not be requested, such as when connecting fails, which internally holds a **[`reqwest::Error`]**.
+    #[error("failed to request {endpoint} in {language}: {source}")]
+    Transport {
+        endpoint: Endpoint,
+        language: Language,
+        #[source]
+        source: reqwest::Error,
+    },
+    /// The server responded with an unsuccessful **[`StatusCode`]**.
+    #[error("requesting {endpoint} in {language} from {url} responded with {status}")]
+    Status {
+        endpoint: Endpoint,
+        language: Language,
+        status: StatusCode,
+        url: String,
+    },
+    /// The data could not be read from or written to the disk, which internally holds a **[`std::io::Error`]**.
+    #[error("failed to access {} for {endpoint} in {language}: {source}", path.display())]
+    Io {
+        endpoint: Endpoint,
+        language: Language,
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /**
+        The data could not be deserialized into its structure, which internally holds a **[`serde_json::Error`]**.
+
+        The `path` is where in the data it failed, such as `[12].Skills[3].Effects[0].Type`,
+        and the `id` is the `Id` of the entity that it failed in, such as the ID of a student or an enemy.
+    */
+    #[error("failed to decode {endpoint} in {language} at `{path}`{}: {source}", id.map(|id| format!(" (ID {id})")).unwrap_or_default())]
+    Decode {
+        endpoint: Endpoint,
+        language: Language,
+        path: String,
+        id: Option<u32>,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The source does not have any data for the **[`Endpoint`]** in the **[`Language`]**.
+    #[error("no data was found for {endpoint} in {language}")]
+    NotFound {
+        endpoint: Endpoint,
+        language: Language,
+    },
+}
+
+impl BlueArchiveError {
+    /// The **[`Endpoint`]** of the data that failed.
+    pub fn endpoint(&self) -> Endpoint {
+        match self {
+            Self::Transport { endpoint, .. }
+            | Self::Status { endpoint, .. }
+            | Self::Io { endpoint, .. }
+            | Self::Decode { endpoint, .. }
+            | Self::NotFound { endpoint, .. } => *endpoint,
+        }
+    }
+
+    /// The **[`Language`]** of the data that failed.
+    pub fn language(&self) -> Language {
+        match self {
+            Self::Transport { language, .. }
+            | Self::Status { language, .. }
+            | Self::Io { language, .. }
+            | Self::Decode { language, .. }
+            | Self::NotFound { language, .. } => *language,
+        }
+    }
+
+    /// Creates a **[`BlueArchiveError::Transport`]** from a **[`reqwest::Error`]**, for use with [`Result::map_err`].
+    pub(crate) fn transport(
+        endpoint: Endpoint,
+        language: Language,
+    ) -> impl FnOnce(reqwest::Error) -> Self {
+        move |source| Self::Transport {
+            endpoint,
+            language,
+            source,
+        }
+    }
+
+    /// Creates a **[`BlueArchiveError::Io`]** from a **[`std::io::Error`]**, for use with [`Result::map_err`].
+    pub(crate) fn io(
+        endpoint: Endpoint,
+        language: Language,
+        path: &Path,
+    ) -> impl FnOnce(std::io::Error) -> Self + '_ {
+        move |source| Self::Io {
+            endpoint,
+            language,
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+/// An element of an **[`Endpoint`]** that failed to be deserialized in a lenient fetch, and was dropped.
+#[derive(Debug)]
+pub struct DecodeFailure {
+    /// The index of the element in the data.
+    pub index: usize,
+    /// The `Id` of the element, if it had one.
+    pub id: Option<u32>,
+    /// Where in the data it failed, such as `[12].Skills[3].Effects[0].Type`.
+    pub path: String,
+    /// Why it failed.
+    pub error: serde_json::Error,
+}
 
-impl std::fmt::Display for BlueArchiveError {
+impl std::fmt::Display for DecodeFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        match self.id {
+            Some(id) => write!(f, "ID {id} at `{}`: {}", self.path, self.error),
+            None => write!(f, "`{}`: {}", self.path, self.error),
+        }
+    }
+}
+
+/**
+    The result of a lenient fetch, such as [`crate::BlueArchiveClient::fetch_all_students_lenient`].
+
+    Each element of the data is deserialized on its own, so that the elements which fail are dropped
+    and reported as a **[`DecodeFailure`]**, instead of failing the entire fetch.
+*/
+#[derive(Debug)]
+pub struct Decoded<T> {
+    /// The elements that were deserialized.
+    pub items: Vec<T>,
+    /// The elements that failed to be deserialized, and why.
+    pub failures: Vec<DecodeFailure>,
+}
+
+impl<T> Decoded<T> {
+    /// Whether every element was deserialized.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The IDs of the elements that failed to be deserialized, excluding the ones without an ID.
+    pub fn failed_ids(&self) -> Vec<u32> {
+        self.failures
+            .iter()
+            .filter_map(|failure| failure.id)
+            .collect()
+    }
+
+    /// Applies a function to each element that was deserialized, keeping the failures.
+    pub(crate) fn map_items(mut self, f: impl FnMut(&mut T)) -> Self {
+        self.items.iter_mut().for_each(f);
+        self
     }
 }
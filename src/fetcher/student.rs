@@ -2,9 +2,11 @@
 
 use std::borrow::Borrow;
 
-use crate::{filter::student::StudentFilterOptions, types::Student, BlueArchiveError, Language};
+use crate::{
+    filter::student::StudentFilterOptions, source::DataSource, types::Student, BlueArchiveClient,
+    BlueArchiveError, Language,
+};
 
-use anyhow::Result;
 use rand::seq::SliceRandom;
 
 /// Allows for caching of **[`Student`]** data with a cost of memory, although functions and accessing of data will be more easier.
@@ -22,6 +24,17 @@ impl StudentFetcher {
         })
     }
 
+    /// Creates a new **[`StudentFetcher`]** by fetching **[`Student`]** data through a **[`BlueArchiveClient`]**, in its default language.
+    ///
+    /// The client can use any **[`DataSource`]**, such as a local directory or fixtures held in memory.
+    pub async fn from_client<S: DataSource>(
+        client: &BlueArchiveClient<S>,
+    ) -> Result<Self, BlueArchiveError> {
+        Ok(Self {
+            students: client.fetch_all_students().await?,
+        })
+    }
+
     #[cfg(feature = "blocking")]
     /// Creates a new student fetcher using the **[`crate::blocking`]** module.
     pub fn new_blocking(language: impl Borrow<Language>) -> Result<Self, BlueArchiveError> {
@@ -30,6 +43,16 @@ impl StudentFetcher {
         })
     }
 
+    #[cfg(feature = "blocking")]
+    /// Creates a new student fetcher through a blocking **[`crate::blocking::BlueArchiveClient`]**, in its default language.
+    pub fn from_blocking_client<S: crate::source::BlockingDataSource>(
+        client: &crate::blocking::BlueArchiveClient<S>,
+    ) -> Result<Self, BlueArchiveError> {
+        Ok(Self {
+            students: client.get_all_students()?,
+        })
+    }
+
     /**
     Gets a **[`Student`]** by a `name` from a set of names.
 
@@ -93,7 +116,7 @@ impl StudentFetcher {
     }
 
     /// Returns **[`StudentFilterOptions`]** to be used for filtering.
-    pub fn filter(&self) -> StudentFilterOptions {
+    pub fn filter(&self) -> StudentFilterOptions<'_> {
         StudentFilterOptions::new(&self.students)
     }
 }
@@ -28,25 +28,36 @@
 //! If you wish to help out, you can. I am a beginner in Rust and I do not mind a few pointers.
 
 pub mod api;
+pub mod audit;
 pub mod enums;
 pub mod errors;
 pub mod fetcher;
 pub mod filter;
 pub(crate) mod serialization;
+pub mod source;
 pub mod types;
 
 #[cfg(feature = "blocking")]
 pub use api::blocking;
 
-pub use api::{currency::*, enemy::*, equipment::*, raid::*, student::*, summon::*};
+pub use api::{
+    audit::*, client::BlueArchiveClient, config::*, currency::*, enemy::*, equipment::*, event::*,
+    furniture::*, item::*, localization::*, raid::*, stage::*, student::*, summon::*, voice::*,
+};
 
 pub use enums::{
-    Armor, BulletType, Club, Language, Position, School, Squad, TacticalRole, WeaponType,
+    Armor, BulletType, Club, Language, Position, Region, School, Squad, TacticalRole, Terrain,
+    WeaponType,
 };
 
-pub use errors::BlueArchiveError;
+pub use errors::{BlueArchiveError, DecodeFailure, Decoded};
 pub use fetcher::StudentFetcher;
 pub use filter::student::StudentFilter;
+pub use source::{BlockingDataSource, DataSource};
 
+/// The default root of the data, which can be changed with [`api::client::BlueArchiveClientBuilder::data_uri`].
 pub const DATA_URI: &str = "https://raw.githubusercontent.com/lonqie/SchaleDB/main/data";
+/// The default root of the images, which can be changed with [`api::client::BlueArchiveClientBuilder::image_uri`].
 pub const IMAGE_DATA_URI: &str = "https://raw.githubusercontent.com/lonqie/SchaleDB/main/images";
+/// The default root of the voice lines, which can be changed with [`api::client::BlueArchiveClientBuilder::voice_uri`].
+pub const VOICE_DATA_URI: &str = "https://static.schale.gg/voice";
@@ -1,7 +1,166 @@
-use serde::{Deserialize, Deserializer};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::{
+    errors::{DecodeFailure, Decoded},
+    source::Endpoint,
+    types::ID,
+    BlueArchiveError, Language, IMAGE_DATA_URI, VOICE_DATA_URI,
+};
 
 pub(crate) fn deserialize_html_encoded_string<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<String, D::Error> {
     Ok(html_escape::decode_html_entities(&String::deserialize(deserializer)?).into())
 }
+
+/// Deserializes a map of strings, skipping the entries that are not strings or numbers.
+pub(crate) fn deserialize_string_map<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<std::collections::HashMap<String, String>, D::Error> {
+    Ok(
+        std::collections::HashMap::<String, Value>::deserialize(deserializer)?
+            .into_iter()
+            .filter_map(|(key, value)| match value {
+                Value::String(string) => Some((key, string)),
+                Value::Number(number) => Some((key, number.to_string())),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// The root of images for types that build urls, until a client replaces it with its configured root.
+pub(crate) fn default_image_uri() -> String {
+    IMAGE_DATA_URI.to_string()
+}
+
+/// The root of voice lines for types that build urls, until a client replaces it with its configured root.
+pub(crate) fn default_voice_uri() -> String {
+    VOICE_DATA_URI.to_string()
+}
+
+/// The amount of a reward when the data leaves it out, which is a single one.
+pub(crate) fn default_amount() -> u32 {
+    1
+}
+
+/// The chance of a reward when the data leaves it out, which is guaranteed.
+pub(crate) fn default_chance() -> f64 {
+    1.0
+}
+
+/// Deserializes either a single value or a sequence of them into a sequence.
+pub(crate) fn deserialize_one_or_many<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// Deserializes a sequence of ids that may be split into groups, such as one per region, into a single sequence without duplicates.
+pub(crate) fn deserialize_flattened_ids<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<ID>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Ids {
+        Flat(Vec<ID>),
+        Grouped(Vec<Vec<ID>>),
+    }
+
+    let ids = match Option::<Ids>::deserialize(deserializer)? {
+        Some(Ids::Flat(ids)) => ids,
+        Some(Ids::Grouped(groups)) => groups.into_iter().flatten().collect(),
+        None => vec![],
+    };
+    let mut unique = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    Ok(unique)
+}
+
+/// Deserializes the data of an **[`Endpoint`]**, keeping track of where in the data it fails.
+pub(crate) fn from_slice<T: DeserializeOwned>(
+    data: &[u8],
+    endpoint: Endpoint,
+    language: Language,
+) -> Result<T, BlueArchiveError> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(data);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let path = error.path().clone();
+        BlueArchiveError::Decode {
+            endpoint,
+            language,
+            id: entity_id(data, &path),
+            path: path.to_string(),
+            source: error.into_inner(),
+        }
+    })
+}
+
+/// Deserializes each element of the data of an **[`Endpoint`]** on its own, dropping and reporting the ones that fail.
+pub(crate) fn from_slice_lenient<T: DeserializeOwned>(
+    data: &[u8],
+    endpoint: Endpoint,
+    language: Language,
+) -> Result<Decoded<T>, BlueArchiveError> {
+    let elements = from_slice::<Vec<Value>>(data, endpoint, language)?;
+    let mut decoded = Decoded {
+        items: Vec::with_capacity(elements.len()),
+        failures: vec![],
+    };
+
+    for (index, element) in elements.into_iter().enumerate() {
+        let id = element
+            .get("Id")
+            .and_then(Value::as_u64)
+            .and_then(|id| u32::try_from(id).ok());
+        match serde_path_to_error::deserialize(element) {
+            Ok(item) => decoded.items.push(item),
+            Err(error) => {
+                let path = match error.path().iter().next() {
+                    Some(_) => format!("[{index}].{}", error.path()),
+                    None => format!("[{index}]"),
+                };
+                decoded.failures.push(DecodeFailure {
+                    index,
+                    id,
+                    path,
+                    error: error.into_inner(),
+                });
+            }
+        }
+    }
+
+    Ok(decoded)
+}
+
+/// Finds the `Id` of the outermost entity along a path into the data, such as the student that failed to deserialize.
+fn entity_id(data: &[u8], path: &serde_path_to_error::Path) -> Option<u32> {
+    let mut value = &serde_json::from_slice::<Value>(data).ok()?;
+    for segment in path.iter() {
+        value = match segment {
+            Segment::Seq { index } => value.get(index)?,
+            Segment::Map { key } => value.get(key)?,
+            Segment::Enum { .. } | Segment::Unknown => return None,
+        };
+        if let Some(id) = value.get("Id").and_then(Value::as_u64) {
+            return u32::try_from(id).ok();
+        }
+    }
+    None
+}
@@ -0,0 +1,488 @@
+//! Contains the **[`CachedSource`]**, which persists the data of another source on disk.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime},
+};
+
+use strum::IntoEnumIterator;
+
+use crate::{BlueArchiveError, Language};
+
+use super::{BlockingDataSource, DataSource, Endpoint};
+
+/// How long cached data is considered fresh by default, which is an hour.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Describes where the data that was last served for an **[`Endpoint`]** came from.
+#[derive(Debug, Clone)]
+pub enum Freshness {
+    /// The data was fetched from the inner source, and then cached.
+    Fetched,
+    /// The data was fetched from the inner source, though it could not be cached, such as when the disk is full.
+    Uncached { error: Arc<BlueArchiveError> },
+    /// The data was served from the cache, as it was within its time to live.
+    Cached { age: Duration },
+    /// The data was served from the cache past its time to live, while it is being refreshed in the background.
+    Revalidating { age: Duration },
+    /// The data was served from the cache past its time to live, as the inner source failed to fetch it,
+    /// or a refresh in the background failed to fetch or cache it.
+    Stale {
+        age: Duration,
+        error: Arc<BlueArchiveError>,
+    },
+}
+
+impl Freshness {
+    /// Whether the data was served past its time to live.
+    pub fn is_stale(&self) -> bool {
+        matches!(self, Self::Revalidating { .. } | Self::Stale { .. })
+    }
+}
+
+/// Information about data that is stored in the cache.
+#[derive(Debug, Clone)]
+pub struct CacheEntry {
+    pub endpoint: Endpoint,
+    pub language: Language,
+    /// The path of the file that holds the data.
+    pub path: PathBuf,
+    /// The size of the data in bytes.
+    pub size: u64,
+    /// How long ago the data was cached.
+    pub age: Duration,
+    /// Whether the data is past its time to live.
+    pub expired: bool,
+}
+
+/**
+    A source that persists the data of another source on disk, keyed by **[`Endpoint`]** and **[`Language`]**.
+
+    - Data that is younger than the [time to live](CachedSource::with_ttl) is served from disk.
+    - Otherwise, it is fetched from the inner source. If that fails, the expired data is served instead,
+      and its **[`Freshness`]** is reported as [`Freshness::Stale`]. If the fetched data cannot be written to the cache,
+      it is still returned, and reported as [`Freshness::Uncached`].
+    - If a [revalidation window](CachedSource::with_stale_while_revalidate) is set, expired data within that window
+      is served right away, while it is refreshed in the background. Only one refresh of an **[`Endpoint`]** in a
+      **[`Language`]** runs at a time, and other reads of it keep being served the expired data meanwhile.
+
+    As a **[`DataSource`]**, it reads and writes files through [`tokio::fs`] and refreshes on a spawned task,
+    so it must be fetched from within a Tokio runtime. As a **[`BlockingDataSource`]**, it refreshes on a spawned thread instead.
+
+    The cache is laid out like ``SchaleDB``, e.g. `<directory>/en/students.json`, so it can also be read by a **[`DirectorySource`](super::DirectorySource)**.
+
+    # Examples
+    ```no_run
+    use std::time::Duration;
+
+    use blue_archive::{
+        source::{CachedSource, Endpoint, HttpSource},
+        BlueArchiveClient, Language,
+    };
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let source = CachedSource::new(HttpSource::new(), "cache")
+            .with_ttl(Duration::from_secs(60 * 60 * 24));
+        let client = BlueArchiveClient::builder().source(source).build();
+
+        let students = client.fetch_all_students().await?;
+        let freshness = client.source().freshness(Endpoint::Students, Language::English);
+        println!("{} students, {:?}", students.len(), freshness);
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug)]
+pub struct CachedSource<S> {
+    inner: Arc<S>,
+    directory: PathBuf,
+    ttl: Duration,
+    stale_while_revalidate: Option<Duration>,
+    freshness: Arc<Mutex<HashMap<(Endpoint, Language), Freshness>>>,
+    revalidating: Arc<Mutex<HashSet<(Endpoint, Language)>>>,
+}
+
+impl<S> Clone for CachedSource<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            directory: self.directory.clone(),
+            ttl: self.ttl,
+            stale_while_revalidate: self.stale_while_revalidate,
+            freshness: self.freshness.clone(),
+            revalidating: self.revalidating.clone(),
+        }
+    }
+}
+
+impl<S> CachedSource<S> {
+    /// Creates a new **[`CachedSource`]** that caches the data of `inner` in the given `directory`.
+    pub fn new(inner: S, directory: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            directory: directory.into(),
+            ttl: DEFAULT_TTL,
+            stale_while_revalidate: None,
+            freshness: Arc::default(),
+            revalidating: Arc::default(),
+        }
+    }
+
+    /// Sets how long cached data is considered fresh, defaults to [`DEFAULT_TTL`].
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Serves expired data that is within the given `window` past its time to live right away,
+    /// while it is refreshed from the inner source in the background.
+    pub fn with_stale_while_revalidate(mut self, window: Duration) -> Self {
+        self.stale_while_revalidate = Some(window);
+        self
+    }
+
+    /// The inner source that data is fetched from.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The directory that data is cached in.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// How long cached data is considered fresh.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The path of the file that caches the data of an **[`Endpoint`]** in a **[`Language`]**.
+    pub fn file_path(&self, endpoint: Endpoint, language: Language) -> PathBuf {
+        self.directory.join(endpoint.path(language))
+    }
+
+    /// The **[`Freshness`]** of the data that was last served for an **[`Endpoint`]** in a **[`Language`]**.
+    pub fn freshness(&self, endpoint: Endpoint, language: Language) -> Option<Freshness> {
+        self.freshness
+            .lock()
+            .unwrap()
+            .get(&(endpoint, language))
+            .cloned()
+    }
+
+    /// Information about the cached data of an **[`Endpoint`]** in a **[`Language`]**, if there is any.
+    pub fn entry(&self, endpoint: Endpoint, language: Language) -> Option<CacheEntry> {
+        let path = self.file_path(endpoint, language);
+        let metadata = std::fs::metadata(&path).ok()?;
+        let age = age_of(&metadata);
+        Some(CacheEntry {
+            endpoint,
+            language,
+            path,
+            size: metadata.len(),
+            age,
+            expired: age > self.ttl,
+        })
+    }
+
+    /// Information about all the data that is stored in the cache.
+    ///
+    /// The data of an endpoint that is not localized, such as [`Endpoint::Config`], is only listed once, in [`Language::English`].
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        Language::iter()
+            .flat_map(|language| {
+                Endpoint::iter()
+                    .filter(move |endpoint| {
+                        endpoint.is_localized() || language == Language::English
+                    })
+                    .filter_map(move |endpoint| self.entry(endpoint, language))
+            })
+            .collect()
+    }
+
+    /// Removes the cached data of an **[`Endpoint`]** in a **[`Language`]**, returning whether there was any.
+    pub fn purge(&self, endpoint: Endpoint, language: Language) -> Result<bool, BlueArchiveError> {
+        self.freshness.lock().unwrap().remove(&(endpoint, language));
+        let path = self.file_path(endpoint, language);
+        match std::fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(BlueArchiveError::io(endpoint, language, &path)(error)),
+        }
+    }
+
+    /// Removes all cached data that is past its time to live, returning how many entries were removed.
+    pub fn purge_expired(&self) -> Result<usize, BlueArchiveError> {
+        let mut purged = 0;
+        for entry in self.entries().into_iter().filter(|entry| entry.expired) {
+            purged += self.purge(entry.endpoint, entry.language)? as usize;
+        }
+        Ok(purged)
+    }
+
+    /// Removes all cached data, returning how many entries were removed.
+    pub fn purge_all(&self) -> Result<usize, BlueArchiveError> {
+        let mut purged = 0;
+        for entry in self.entries() {
+            purged += self.purge(entry.endpoint, entry.language)? as usize;
+        }
+        Ok(purged)
+    }
+
+    fn record(&self, endpoint: Endpoint, language: Language, freshness: Freshness) {
+        self.freshness
+            .lock()
+            .unwrap()
+            .insert((endpoint, language), freshness);
+    }
+
+    /// Marks the data of an **[`Endpoint`]** in a **[`Language`]** as being refreshed,
+    /// or returns [`None`] if a refresh of it is already running.
+    fn begin_revalidation(&self, endpoint: Endpoint, language: Language) -> Option<Revalidation> {
+        let key = (endpoint, language);
+        self.revalidating
+            .lock()
+            .unwrap()
+            .insert(key)
+            .then(|| Revalidation {
+                revalidating: self.revalidating.clone(),
+                key,
+            })
+    }
+
+    /// Records data that was fetched as **[`Fetched`](Freshness::Fetched)**, or as **[`Uncached`](Freshness::Uncached)**
+    /// if it could not be written, as failing to cache data does not fail the fetch.
+    fn record_written(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+        path: &Path,
+        written: std::io::Result<()>,
+    ) {
+        let freshness = match written {
+            Ok(()) => Freshness::Fetched,
+            Err(error) => Freshness::Uncached {
+                error: Arc::new(BlueArchiveError::io(endpoint, language, path)(error)),
+            },
+        };
+        self.record(endpoint, language, freshness);
+    }
+
+    /// Decides what to do with cached data of the given `age`.
+    fn plan(&self, age: Duration) -> Plan {
+        if age <= self.ttl {
+            Plan::Serve
+        } else if self
+            .stale_while_revalidate
+            .is_some_and(|window| age <= self.ttl + window)
+        {
+            Plan::Revalidate
+        } else {
+            Plan::Refetch
+        }
+    }
+}
+
+/// A refresh that is running, which is no longer marked as running once dropped, even if the refresh panicked.
+struct Revalidation {
+    revalidating: Arc<Mutex<HashSet<(Endpoint, Language)>>>,
+    key: (Endpoint, Language),
+}
+
+impl Drop for Revalidation {
+    fn drop(&mut self) {
+        if let Ok(mut revalidating) = self.revalidating.lock() {
+            revalidating.remove(&self.key);
+        }
+    }
+}
+
+/// What to do with cached data, depending on its age.
+enum Plan {
+    Serve,
+    Revalidate,
+    Refetch,
+}
+
+fn age_of(metadata: &std::fs::Metadata) -> Duration {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .unwrap_or_default()
+}
+
+/// The path that data is written to before it is moved into place, so that a cached file is never partially written.
+///
+/// Each write gets its own path in the same directory, e.g. `students.json.1234.0.tmp`,
+/// so that concurrent writes of the same data, such as a revalidation and a fetch, never write into the same file.
+fn temporary_path(path: &Path) -> PathBuf {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+    let write = WRITES.fetch_add(1, Ordering::Relaxed);
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.{write}.tmp", std::process::id()));
+    path.with_file_name(name)
+}
+
+async fn read_cached(path: &Path) -> Option<(Arc<[u8]>, Duration)> {
+    let age = age_of(&tokio::fs::metadata(path).await.ok()?);
+    Some((Arc::from(tokio::fs::read(path).await.ok()?), age))
+}
+
+async fn write_cached(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let temporary = temporary_path(path);
+    let written = match tokio::fs::write(&temporary, data).await {
+        Ok(()) => tokio::fs::rename(&temporary, path).await,
+        Err(error) => Err(error),
+    };
+    if written.is_err() {
+        let _ = tokio::fs::remove_file(&temporary).await;
+    }
+    written
+}
+
+fn read_cached_blocking(path: &Path) -> Option<(Arc<[u8]>, Duration)> {
+    let age = age_of(&std::fs::metadata(path).ok()?);
+    Some((Arc::from(std::fs::read(path).ok()?), age))
+}
+
+fn write_cached_blocking(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temporary = temporary_path(path);
+    let written = std::fs::write(&temporary, data).and_then(|()| std::fs::rename(&temporary, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temporary);
+    }
+    written
+}
+
+impl<S: DataSource + 'static> DataSource for CachedSource<S> {
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let path = self.file_path(endpoint, language);
+        let cached = read_cached(&path).await;
+
+        if let Some((data, age)) = &cached {
+            match self.plan(*age) {
+                Plan::Serve => {
+                    self.record(endpoint, language, Freshness::Cached { age: *age });
+                    return Ok(data.clone());
+                }
+                Plan::Revalidate => {
+                    self.record(endpoint, language, Freshness::Revalidating { age: *age });
+                    let Some(revalidation) = self.begin_revalidation(endpoint, language) else {
+                        return Ok(data.clone());
+                    };
+                    let this = self.clone();
+                    let age = *age;
+                    tokio::spawn(async move {
+                        let _revalidation = revalidation;
+                        let error = match this.inner.fetch(endpoint, language).await {
+                            Ok(data) => match write_cached(&path, &data).await {
+                                Ok(()) => {
+                                    return this.record(endpoint, language, Freshness::Fetched)
+                                }
+                                Err(error) => {
+                                    BlueArchiveError::io(endpoint, language, &path)(error)
+                                }
+                            },
+                            Err(error) => error,
+                        };
+                        let error = Arc::new(error);
+                        this.record(endpoint, language, Freshness::Stale { age, error });
+                    });
+                    return Ok(data.clone());
+                }
+                Plan::Refetch => (),
+            }
+        }
+
+        match self.inner.fetch(endpoint, language).await {
+            Ok(data) => {
+                let written = write_cached(&path, &data).await;
+                self.record_written(endpoint, language, &path, written);
+                Ok(data)
+            }
+            Err(error) => match cached {
+                Some((data, age)) => {
+                    let error = Arc::new(error);
+                    self.record(endpoint, language, Freshness::Stale { age, error });
+                    Ok(data)
+                }
+                None => Err(error),
+            },
+        }
+    }
+}
+
+impl<S: BlockingDataSource + Send + Sync + 'static> BlockingDataSource for CachedSource<S> {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let path = self.file_path(endpoint, language);
+        let cached = read_cached_blocking(&path);
+
+        if let Some((data, age)) = &cached {
+            match self.plan(*age) {
+                Plan::Serve => {
+                    self.record(endpoint, language, Freshness::Cached { age: *age });
+                    return Ok(data.clone());
+                }
+                Plan::Revalidate => {
+                    self.record(endpoint, language, Freshness::Revalidating { age: *age });
+                    let Some(revalidation) = self.begin_revalidation(endpoint, language) else {
+                        return Ok(data.clone());
+                    };
+                    let this = self.clone();
+                    let age = *age;
+                    std::thread::spawn(move || {
+                        let _revalidation = revalidation;
+                        let error = match this.inner.get(endpoint, language) {
+                            Ok(data) => match write_cached_blocking(&path, &data) {
+                                Ok(()) => {
+                                    return this.record(endpoint, language, Freshness::Fetched)
+                                }
+                                Err(error) => {
+                                    BlueArchiveError::io(endpoint, language, &path)(error)
+                                }
+                            },
+                            Err(error) => error,
+                        };
+                        let error = Arc::new(error);
+                        this.record(endpoint, language, Freshness::Stale { age, error });
+                    });
+                    return Ok(data.clone());
+                }
+                Plan::Refetch => (),
+            }
+        }
+
+        match self.inner.get(endpoint, language) {
+            Ok(data) => {
+                let written = write_cached_blocking(&path, &data);
+                self.record_written(endpoint, language, &path, written);
+                Ok(data)
+            }
+            Err(error) => match cached {
+                Some((data, age)) => {
+                    let error = Arc::new(error);
+                    self.record(endpoint, language, Freshness::Stale { age, error });
+                    Ok(data)
+                }
+                None => Err(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Contains the **[`DirectorySource`]**.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{BlueArchiveError, Language};
+
+use super::{BlockingDataSource, DataSource, Endpoint};
+
+/// A source that reads data from a local directory, such as the `data/` directory of a checked-out ``SchaleDB``.
+///
+/// The directory is expected to be laid out like ``SchaleDB``, e.g. `<path>/en/students.json`.
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    path: PathBuf,
+}
+
+impl DirectorySource {
+    /// Creates a new **[`DirectorySource`]** that reads from the given `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The directory that data is read from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The path of the file that holds the data of an **[`Endpoint`]** in a **[`Language`]**.
+    pub fn file_path(&self, endpoint: Endpoint, language: Language) -> PathBuf {
+        self.path.join(endpoint.path(language))
+    }
+}
+
+/// A file that does not exist is data that was not found, while anything else failed to be read.
+fn read_error(
+    endpoint: Endpoint,
+    language: Language,
+    path: &Path,
+    error: std::io::Error,
+) -> BlueArchiveError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => BlueArchiveError::NotFound { endpoint, language },
+        _ => BlueArchiveError::io(endpoint, language, path)(error),
+    }
+}
+
+impl DataSource for DirectorySource {
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let path = self.file_path(endpoint, language);
+        tokio::fs::read(&path)
+            .await
+            .map(Arc::from)
+            .map_err(|error| read_error(endpoint, language, &path, error))
+    }
+}
+
+impl BlockingDataSource for DirectorySource {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let path = self.file_path(endpoint, language);
+        std::fs::read(&path)
+            .map(Arc::from)
+            .map_err(|error| read_error(endpoint, language, &path, error))
+    }
+}
@@ -0,0 +1,506 @@
+//! Contains the **[`HttpSource`]**, and if elligble, the **[`BlockingHttpSource`]**.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::{
+    header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    Client, StatusCode,
+};
+
+use crate::{BlueArchiveError, Language, DATA_URI};
+
+use super::{DataSource, Endpoint, RetryPolicy};
+
+/// Removes the trailing slashes of a root, as urls are joined with a `/` onto them.
+pub(crate) fn trim_uri(uri: String) -> String {
+    uri.trim_end_matches('/').to_string()
+}
+
+/// Builds the url of an **[`Endpoint`]** in a **[`Language`]**, relative to the `data_uri`.
+fn endpoint_url(data_uri: &str, endpoint: Endpoint, language: Language) -> String {
+    format!("{}/{}", data_uri, endpoint.path(language))
+}
+
+/// Turns an unsuccessful **[`StatusCode`]** into an error, where `404 Not Found` means that there is no such data.
+fn check_status(
+    endpoint: Endpoint,
+    language: Language,
+    url: &str,
+    status: StatusCode,
+) -> Result<(), BlueArchiveError> {
+    match status {
+        status if status.is_success() => Ok(()),
+        StatusCode::NOT_FOUND => Err(BlueArchiveError::NotFound { endpoint, language }),
+        status => Err(BlueArchiveError::Status {
+            endpoint,
+            language,
+            status,
+            url: url.to_string(),
+        }),
+    }
+}
+
+/// The max amount of bytes of response bodies that an http source remembers by default, which is 64 MiB.
+pub const DEFAULT_REMEMBERED_BYTES: usize = 64 * 1024 * 1024;
+
+/// A response that was remembered alongside its `ETag` and `Last-Modified` validators.
+#[derive(Debug, Clone)]
+struct Validated {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    data: Arc<[u8]>,
+    /// When the response was last used, to evict the least recently used ones first.
+    last_used: u64,
+}
+
+/// The remembered responses, and the amount of bytes of their bodies.
+#[derive(Debug, Default)]
+struct Remembered {
+    responses: HashMap<(Endpoint, Language), Validated>,
+    bytes: usize,
+    uses: u64,
+}
+
+impl Remembered {
+    fn remove(&mut self, key: &(Endpoint, Language)) {
+        if let Some(validated) = self.responses.remove(key) {
+            self.bytes -= validated.data.len();
+        }
+    }
+}
+
+/// Remembers the validators of each **[`Endpoint`]** response, shared between clones of a source.
+#[derive(Debug, Clone, Default)]
+struct Validators(Arc<Mutex<Remembered>>);
+
+impl Validators {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Option<Validated> {
+        let mut remembered = self.0.lock().unwrap();
+        remembered.uses += 1;
+        let uses = remembered.uses;
+        let validated = remembered.responses.get_mut(&(endpoint, language))?;
+        validated.last_used = uses;
+        Some(validated.clone())
+    }
+
+    /// Adds the `If-None-Match` and `If-Modified-Since` headers of a remembered response.
+    fn conditional_headers(validated: &Validated) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            (IF_NONE_MATCH, &validated.etag),
+            (IF_MODIFIED_SINCE, &validated.last_modified),
+        ] {
+            if let Some(value) = value.as_ref().and_then(|value| value.parse().ok()) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+
+    /// Remembers a response if it has any validators,
+    /// evicting the least recently used responses until the bodies fit in `limit` bytes.
+    fn remember(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+        headers: &HeaderMap,
+        data: &Arc<[u8]>,
+        limit: usize,
+    ) {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        };
+        let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+        let key = (endpoint, language);
+        let mut remembered = self.0.lock().unwrap();
+        remembered.remove(&key);
+        if (etag.is_none() && last_modified.is_none()) || data.len() > limit {
+            return;
+        }
+
+        while remembered.bytes + data.len() > limit {
+            let Some(oldest) = remembered
+                .responses
+                .iter()
+                .min_by_key(|(_, validated)| validated.last_used)
+                .map(|(key, _)| *key)
+            else {
+                break;
+            };
+            remembered.remove(&oldest);
+        }
+        remembered.uses += 1;
+        remembered.bytes += data.len();
+        let last_used = remembered.uses;
+        remembered.responses.insert(
+            key,
+            Validated {
+                etag,
+                last_modified,
+                data: Arc::clone(data),
+                last_used,
+            },
+        );
+    }
+
+    /// The amount of bytes of the bodies that are remembered.
+    fn bytes(&self) -> usize {
+        self.0.lock().unwrap().bytes
+    }
+
+    fn clear(&self) {
+        let mut remembered = self.0.lock().unwrap();
+        remembered.responses.clear();
+        remembered.bytes = 0;
+    }
+}
+
+/**
+    A **[`DataSource`]** that fetches data over http, relative to a root such as [`DATA_URI`].
+
+    The `ETag` and `Last-Modified` validators of each response are remembered alongside its body, and sent back with
+    `If-None-Match` and `If-Modified-Since` on later requests. If the server responds with `304 Not Modified`,
+    the remembered body is returned as is, without being downloaded or copied again, though it is still decoded again
+    by the client.
+
+    The remembered bodies are bounded to [`DEFAULT_REMEMBERED_BYTES`] by default, evicting the least recently used ones,
+    see [`HttpSource::with_remembered_bytes`].
+*/
+#[derive(Debug, Clone)]
+pub struct HttpSource {
+    client: Client,
+    data_uri: String,
+    conditional_requests: bool,
+    remembered_bytes: usize,
+    validators: Validators,
+    retry_policy: RetryPolicy,
+}
+
+impl HttpSource {
+    /// Creates a new **[`HttpSource`]** that fetches from [`DATA_URI`].
+    pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Creates a new **[`HttpSource`]** from an already configured **[`reqwest::Client`]**.
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client,
+            data_uri: DATA_URI.to_string(),
+            conditional_requests: true,
+            remembered_bytes: DEFAULT_REMEMBERED_BYTES,
+            validators: Validators::default(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the root of the data that is fetched, defaults to [`DATA_URI`].
+    ///
+    /// This can point to a mirror, a pinned commit or a local server, e.g. `http://localhost:8080/data`.
+    pub fn with_data_uri(mut self, data_uri: impl Into<String>) -> Self {
+        self.data_uri = trim_uri(data_uri.into());
+        self
+    }
+
+    /// Sets whether validators are remembered and sent back to make conditional requests, which is enabled by default.
+    pub fn with_conditional_requests(mut self, enabled: bool) -> Self {
+        self.conditional_requests = enabled;
+        self
+    }
+
+    /// Sets the max amount of bytes of response bodies that are remembered, defaults to [`DEFAULT_REMEMBERED_BYTES`].
+    ///
+    /// The least recently used responses are forgotten first, and a body larger than this is never remembered,
+    /// so `0` only keeps the conditional requests from being made.
+    pub fn with_remembered_bytes(mut self, remembered_bytes: usize) -> Self {
+        self.remembered_bytes = remembered_bytes;
+        self
+    }
+
+    /// The amount of bytes of the response bodies that are currently remembered.
+    pub fn remembered_bytes(&self) -> usize {
+        self.validators.bytes()
+    }
+
+    /// Forgets the validators and data of all the responses that were remembered.
+    pub fn clear_validators(&self) {
+        self.validators.clear();
+    }
+
+    /// Sets the **[`RetryPolicy`]** of failed requests, defaults to [`RetryPolicy::default`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Replaces the **[`reqwest::Client`]**, while keeping the rest of the configuration.
+    pub(crate) fn set_client(&mut self, client: Client) {
+        self.client = client;
+    }
+
+    /// The **[`RetryPolicy`]** of failed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The **[`reqwest::Client`]** that requests are made with.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The root of the data that is fetched.
+    pub fn data_uri(&self) -> &str {
+        &self.data_uri
+    }
+
+    /// Remembers the validators of a response if conditional requests are enabled, and returns its data.
+    fn remember(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+        headers: &HeaderMap,
+        data: &[u8],
+    ) -> Arc<[u8]> {
+        let data = Arc::from(data);
+        if self.conditional_requests {
+            self.validators
+                .remember(endpoint, language, headers, &data, self.remembered_bytes);
+        }
+        data
+    }
+}
+
+impl Default for HttpSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataSource for HttpSource {
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let url = endpoint_url(&self.data_uri, endpoint, language);
+        let validated = self
+            .conditional_requests
+            .then(|| self.validators.get(endpoint, language))
+            .flatten();
+
+        // An attempt covers both sending the request and reading the body, so a body that is cut off is retried too.
+        let mut attempt = 1;
+        loop {
+            let retry = |error: reqwest::Error| {
+                self.retry_policy
+                    .retry_error(attempt, &error)
+                    .ok_or_else(|| BlueArchiveError::transport(endpoint, language)(error))
+            };
+            let mut request = self.client.get(&url);
+            if let Some(validated) = &validated {
+                request = request.headers(Validators::conditional_headers(validated));
+            }
+            let delay = match request.send().await {
+                Ok(response) => match self.retry_policy.retry_status(
+                    attempt,
+                    response.status(),
+                    response.headers(),
+                ) {
+                    Some(delay) => delay,
+                    None => {
+                        if let (StatusCode::NOT_MODIFIED, Some(validated)) =
+                            (response.status(), &validated)
+                        {
+                            return Ok(Arc::clone(&validated.data));
+                        }
+
+                        check_status(endpoint, language, &url, response.status())?;
+                        let headers = response.headers().clone();
+                        match response.bytes().await {
+                            Ok(data) => {
+                                return Ok(self.remember(endpoint, language, &headers, &data))
+                            }
+                            Err(error) => retry(error)?,
+                        }
+                    }
+                },
+                Err(error) => retry(error)?,
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// A **[`BlockingDataSource`](super::BlockingDataSource)** that gets data over http, relative to a root such as [`DATA_URI`].
+///
+/// Like the **[`HttpSource`]**, it makes conditional requests with the validators and bodies of previous responses,
+/// bounded by [`BlockingHttpSource::with_remembered_bytes`], and retries failed requests according to its **[`RetryPolicy`]**.
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
+pub struct BlockingHttpSource {
+    client: reqwest::blocking::Client,
+    data_uri: String,
+    conditional_requests: bool,
+    remembered_bytes: usize,
+    validators: Validators,
+    retry_policy: RetryPolicy,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingHttpSource {
+    /// Creates a new **[`BlockingHttpSource`]** that gets from [`DATA_URI`].
+    pub fn new() -> Self {
+        Self::with_client(reqwest::blocking::Client::new())
+    }
+
+    /// Creates a new **[`BlockingHttpSource`]** from an already configured **[`reqwest::blocking::Client`]**.
+    pub fn with_client(client: reqwest::blocking::Client) -> Self {
+        Self {
+            client,
+            data_uri: DATA_URI.to_string(),
+            conditional_requests: true,
+            remembered_bytes: DEFAULT_REMEMBERED_BYTES,
+            validators: Validators::default(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the root of the data that is fetched, defaults to [`DATA_URI`].
+    pub fn with_data_uri(mut self, data_uri: impl Into<String>) -> Self {
+        self.data_uri = trim_uri(data_uri.into());
+        self
+    }
+
+    /// Sets whether validators are remembered and sent back to make conditional requests, which is enabled by default.
+    pub fn with_conditional_requests(mut self, enabled: bool) -> Self {
+        self.conditional_requests = enabled;
+        self
+    }
+
+    /// Sets the max amount of bytes of response bodies that are remembered, defaults to [`DEFAULT_REMEMBERED_BYTES`].
+    ///
+    /// The least recently used responses are forgotten first, and a body larger than this is never remembered,
+    /// so `0` only keeps the conditional requests from being made.
+    pub fn with_remembered_bytes(mut self, remembered_bytes: usize) -> Self {
+        self.remembered_bytes = remembered_bytes;
+        self
+    }
+
+    /// The amount of bytes of the response bodies that are currently remembered.
+    pub fn remembered_bytes(&self) -> usize {
+        self.validators.bytes()
+    }
+
+    /// Forgets the validators and data of all the responses that were remembered.
+    pub fn clear_validators(&self) {
+        self.validators.clear();
+    }
+
+    /// Sets the **[`RetryPolicy`]** of failed requests, defaults to [`RetryPolicy::default`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Replaces the **[`reqwest::blocking::Client`]**, while keeping the rest of the configuration.
+    pub(crate) fn set_client(&mut self, client: reqwest::blocking::Client) {
+        self.client = client;
+    }
+
+    /// The **[`RetryPolicy`]** of failed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The **[`reqwest::blocking::Client`]** that requests are made with.
+    pub fn client(&self) -> &reqwest::blocking::Client {
+        &self.client
+    }
+
+    /// The root of the data that is fetched.
+    pub fn data_uri(&self) -> &str {
+        &self.data_uri
+    }
+
+    /// Remembers the validators of a response if conditional requests are enabled, and returns its data.
+    fn remember(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+        headers: &HeaderMap,
+        data: &[u8],
+    ) -> Arc<[u8]> {
+        let data = Arc::from(data);
+        if self.conditional_requests {
+            self.validators
+                .remember(endpoint, language, headers, &data, self.remembered_bytes);
+        }
+        data
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Default for BlockingHttpSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl super::BlockingDataSource for BlockingHttpSource {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        let url = endpoint_url(&self.data_uri, endpoint, language);
+        let validated = self
+            .conditional_requests
+            .then(|| self.validators.get(endpoint, language))
+            .flatten();
+
+        let mut attempt = 1;
+        loop {
+            let retry = |error: reqwest::Error| {
+                self.retry_policy
+                    .retry_error(attempt, &error)
+                    .ok_or_else(|| BlueArchiveError::transport(endpoint, language)(error))
+            };
+            let mut request = self.client.get(&url);
+            if let Some(validated) = &validated {
+                request = request.headers(Validators::conditional_headers(validated));
+            }
+            let delay = match request.send() {
+                Ok(response) => match self.retry_policy.retry_status(
+                    attempt,
+                    response.status(),
+                    response.headers(),
+                ) {
+                    Some(delay) => delay,
+                    None => {
+                        if let (StatusCode::NOT_MODIFIED, Some(validated)) =
+                            (response.status(), &validated)
+                        {
+                            return Ok(Arc::clone(&validated.data));
+                        }
+
+                        check_status(endpoint, language, &url, response.status())?;
+                        let headers = response.headers().clone();
+                        match response.bytes() {
+                            Ok(data) => {
+                                return Ok(self.remember(endpoint, language, &headers, &data))
+                            }
+                            Err(error) => retry(error)?,
+                        }
+                    }
+                },
+                Err(error) => retry(error)?,
+            };
+            std::thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Contains the **[`MemorySource`]**.
+
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{BlueArchiveError, Language};
+
+use super::{BlockingDataSource, DataSource, Endpoint};
+
+/**
+    A source that holds the data of each **[`Endpoint`]** and **[`Language`]** in memory, which is useful for fixtures.
+
+    # Examples
+    ```
+    use blue_archive::{
+        source::{Endpoint, MemorySource},
+        BlueArchiveClient, Language,
+    };
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let source = MemorySource::new().with(Endpoint::Currency, Language::English, "[]");
+        let client = BlueArchiveClient::builder().source(source).build();
+        assert!(client.fetch_all_currencies().await?.is_empty());
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct MemorySource {
+    data: HashMap<(Endpoint, Language), Arc<[u8]>>,
+}
+
+impl MemorySource {
+    /// Creates a new, empty **[`MemorySource`]**.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the data of an **[`Endpoint`]** in a **[`Language`]**, replacing the previous data if there was any.
+    pub fn insert(&mut self, endpoint: Endpoint, language: Language, data: impl Into<Vec<u8>>) {
+        self.data
+            .insert((endpoint, language), Arc::from(data.into()));
+    }
+
+    /// Inserts the data of an **[`Endpoint`]** in a **[`Language`]**, and returns itself for chaining.
+    pub fn with(
+        mut self,
+        endpoint: Endpoint,
+        language: Language,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.insert(endpoint, language, data);
+        self
+    }
+
+    fn data(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        self.data
+            .get(&(endpoint, language))
+            .cloned()
+            .ok_or(BlueArchiveError::NotFound { endpoint, language })
+    }
+}
+
+impl DataSource for MemorySource {
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> Result<Arc<[u8]>, BlueArchiveError> {
+        self.data(endpoint, language)
+    }
+}
+
+impl BlockingDataSource for MemorySource {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        self.data(endpoint, language)
+    }
+}
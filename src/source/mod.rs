@@ -0,0 +1,128 @@
+//! Contains the **[`DataSource`]** and **[`BlockingDataSource`]** traits, which is where the raw data of an **[`Endpoint`]** comes from.
+//!
+//! The crate provides the following sources, though anything can be one by implementing the traits:
+//! - **[`HttpSource`]**, fetching from a root such as [`crate::DATA_URI`].
+//! - **[`DirectorySource`]**, reading from a local ``SchaleDB`` `data/` directory.
+//! - **[`MemorySource`]**, holding the data in memory, which is useful for fixtures.
+//!
+//! Sources can also be layered, such as wrapping a source in a **[`CachedSource`]** to persist its data on disk.
+
+pub mod cache;
+pub mod directory;
+pub mod http;
+pub mod memory;
+pub mod retry;
+
+use std::{future::Future, sync::Arc};
+
+use strum_macros::{Display, EnumIter};
+
+use crate::{BlueArchiveError, Language};
+
+pub use cache::CachedSource;
+pub use directory::DirectorySource;
+#[cfg(feature = "blocking")]
+pub use http::BlockingHttpSource;
+pub use http::HttpSource;
+pub use memory::MemorySource;
+pub use retry::RetryPolicy;
+
+/// Contains the endpoints for the data, they mainly just represent the path of what data is obtained.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum Endpoint {
+    Localization,
+    Voice,
+    Events,
+    Furniture,
+    Items,
+    Stages,
+    Config,
+    Enemies,
+    Equipment,
+    Currency,
+    Raids,
+    Students,
+    Summons,
+}
+
+impl Endpoint {
+    /// The name of the file that holds the data of the endpoint, e.g. `students.json`.
+    pub fn file_name(&self) -> String {
+        format!("{}.json", self.to_string().to_lowercase())
+    }
+
+    /// Whether the data of the endpoint differs by **[`Language`]**, which is not the case for [`Endpoint::Config`].
+    pub fn is_localized(&self) -> bool {
+        !matches!(self, Self::Config)
+    }
+
+    /// The path of the data of the endpoint in a **[`Language`]**, relative to the root of the data, e.g. `en/students.json`.
+    pub fn path(&self, language: Language) -> String {
+        match self.is_localized() {
+            true => format!("{}/{}", language.id(), self.file_name()),
+            false => self.file_name(),
+        }
+    }
+}
+
+/**
+    An asynchronous source of raw data, returning the bytes of an **[`Endpoint`]** in a **[`Language`]**.
+
+    The bytes are shared, so that a source which keeps them around, such as a **[`MemorySource`]**,
+    can hand them out without copying them.
+
+    # Examples
+    ```
+    use blue_archive::{
+        source::{DataSource, Endpoint},
+        BlueArchiveClient, BlueArchiveError, Language,
+    };
+    use std::sync::Arc;
+
+    /// A source that always returns the same currencies.
+    struct Fixture;
+
+    impl DataSource for Fixture {
+        async fn fetch(&self, _: Endpoint, _: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+            Ok(Arc::from(b"[]".as_slice()))
+        }
+    }
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let client = BlueArchiveClient::builder().source(Fixture).build();
+        assert!(client.fetch_all_currencies().await?.is_empty());
+        Ok(())
+    }
+    ```
+*/
+pub trait DataSource: Send + Sync {
+    /// Fetches the raw data of an **[`Endpoint`]** in the given **[`Language`]**.
+    fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> impl Future<Output = Result<Arc<[u8]>, BlueArchiveError>> + Send;
+}
+
+/// A blocking source of raw data, returning the bytes of an **[`Endpoint`]** in a **[`Language`]**.
+pub trait BlockingDataSource {
+    /// Gets the raw data of an **[`Endpoint`]** in the given **[`Language`]**.
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError>;
+}
+
+impl<S: DataSource> DataSource for Arc<S> {
+    fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> impl Future<Output = Result<Arc<[u8]>, BlueArchiveError>> + Send {
+        (**self).fetch(endpoint, language)
+    }
+}
+
+impl<S: BlockingDataSource + ?Sized> BlockingDataSource for Arc<S> {
+    fn get(&self, endpoint: Endpoint, language: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        (**self).get(endpoint, language)
+    }
+}
@@ -0,0 +1,187 @@
+//! Contains the **[`RetryPolicy`]**, which decides how the http sources retry failed requests.
+
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
+
+use rand::Rng;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    StatusCode,
+};
+
+/// The status codes that are retried by default, which are usually transient.
+pub const DEFAULT_RETRYABLE_STATUS_CODES: [StatusCode; 6] = [
+    StatusCode::REQUEST_TIMEOUT,
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/**
+    Decides whether, and after how long, a failed request is retried by a **[`HttpSource`](super::HttpSource)**.
+
+    A request is retried when it could not connect, timed out, was cut off while reading its body,
+    or responded with one of the retryable status codes.
+    Between attempts, the delay doubles from the base delay up to the max delay, and with jitter,
+    a random delay between half and all of it is used instead. If the response has a `Retry-After` header,
+    it is honoured instead, though still capped to the max delay.
+
+    # Examples
+    ```
+    use std::time::Duration;
+
+    use blue_archive::{
+        source::{retry::RetryPolicy, HttpSource},
+        BlueArchiveClient,
+    };
+
+    let policy = RetryPolicy::new()
+        .with_max_attempts(5)
+        .with_backoff(Duration::from_millis(250), Duration::from_secs(10));
+    let client = BlueArchiveClient::builder()
+        .source(HttpSource::new().with_retry_policy(policy))
+        .build();
+    ```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_after: bool,
+    status_codes: HashSet<StatusCode>,
+}
+
+impl RetryPolicy {
+    /// Creates a new **[`RetryPolicy`]** of 3 attempts, with a jittered backoff from 500 milliseconds to 30 seconds.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_after: true,
+            status_codes: HashSet::from(DEFAULT_RETRYABLE_STATUS_CODES),
+        }
+    }
+
+    /// Creates a **[`RetryPolicy`]** that never retries, making a single attempt.
+    pub fn none() -> Self {
+        Self::new().with_max_attempts(1)
+    }
+
+    /// Sets the max amount of attempts, including the first one. Anything below 1 is treated as 1.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry, and the max delay that it doubles up to.
+    pub fn with_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay.max(base_delay);
+        self
+    }
+
+    /// Sets whether the delay is randomized between half and all of it, which is enabled by default.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether the `Retry-After` header of a response is honoured, which is enabled by default.
+    pub fn with_retry_after(mut self, retry_after: bool) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// Sets the status codes that are retried, defaults to [`DEFAULT_RETRYABLE_STATUS_CODES`].
+    pub fn with_retryable_status_codes(
+        mut self,
+        status_codes: impl IntoIterator<Item = StatusCode>,
+    ) -> Self {
+        self.status_codes = status_codes.into_iter().collect();
+        self
+    }
+
+    /// The max amount of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Whether a response with the given **[`StatusCode`]** is retried.
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.status_codes.contains(&status)
+    }
+
+    /// Whether a request that failed with the given **[`reqwest::Error`]** is retried.
+    ///
+    /// Errors while reading the body are retried as well, as a body that is cut off shows up as a body or decode error.
+    pub fn is_retryable_error(&self, error: &reqwest::Error) -> bool {
+        error.is_connect()
+            || error.is_timeout()
+            || error.is_request()
+            || error.is_body()
+            || error.is_decode()
+    }
+
+    /// The delay of the backoff after the given attempt, without considering any `Retry-After` header.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        match self.jitter && !delay.is_zero() {
+            true => rand::thread_rng().gen_range(delay / 2..=delay),
+            false => delay,
+        }
+    }
+
+    /// Returns the delay before retrying a response with the given status, or `None` if it is not retried.
+    pub(crate) fn retry_status(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable_status(status) {
+            return None;
+        }
+        let retry_after = self
+            .retry_after
+            .then(|| parse_retry_after(headers))
+            .flatten();
+        Some(match retry_after {
+            Some(retry_after) => retry_after.min(self.max_delay),
+            None => self.backoff(attempt),
+        })
+    }
+
+    /// Returns the delay before retrying a request that failed with an error, or `None` if it is not retried.
+    pub(crate) fn retry_error(&self, attempt: u32, error: &reqwest::Error) -> Option<Duration> {
+        (attempt < self.max_attempts && self.is_retryable_error(error))
+            .then(|| self.backoff(attempt))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a `Retry-After` header, which is either an amount of seconds or a http date.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => httpdate::parse_http_date(value)
+            .ok()
+            .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default()),
+    }
+}
@@ -0,0 +1,230 @@
+//! Contains the [`Config`] structure and its respective structures.
+
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Region, Terrain};
+
+use super::Student;
+
+/**
+    **The configuration of ``SchaleDB``**, which holds what is currently happening in each region,
+    such as the gacha banners, events and raids.
+
+    # Examples
+    ```
+    use blue_archive::Region;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let config = blue_archive::fetch_config().await?;
+        let students = blue_archive::fetch_all_students(blue_archive::Language::English).await?;
+        for student in config.students_on_banner(Region::Global, chrono::Utc::now(), &students) {
+            println!("{} is on a banner in Global", student.full_name_last());
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Config {
+    /// The configuration of each region.
+    #[serde(default)]
+    pub regions: Vec<RegionConfig>,
+    /// Everything else of the configuration that is not represented yet.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl Config {
+    /// Gets the **[`RegionConfig`]** of a **[`Region`]**.
+    pub fn region(&self, region: Region) -> Option<&RegionConfig> {
+        self.regions
+            .iter()
+            .find(|config| config.region() == Some(region))
+    }
+
+    /// Gets the **[`GachaBanners`][`GachaBanner`]** of a **[`Region`]** that are active at the given instant.
+    pub fn banners_at(&self, region: Region, at: DateTime<Utc>) -> Vec<&GachaBanner> {
+        self.region(region)
+            .map(|config| config.banners_at(at))
+            .unwrap_or_default()
+    }
+
+    /// Gets the **[`CurrentEvents`][`CurrentEvent`]** of a **[`Region`]** that are active at the given instant.
+    pub fn events_at(&self, region: Region, at: DateTime<Utc>) -> Vec<&CurrentEvent> {
+        self.region(region)
+            .map(|config| config.events_at(at))
+            .unwrap_or_default()
+    }
+
+    /// Gets the **[`Students`][`Student`]** out of the given ones that are on a banner of a **[`Region`]** at the given instant.
+    pub fn students_on_banner<'a>(
+        &self,
+        region: Region,
+        at: DateTime<Utc>,
+        students: &'a [Student],
+    ) -> Vec<&'a Student> {
+        let banners = self.banners_at(region, at);
+        students
+            .iter()
+            .filter(|student| banners.iter().any(|banner| banner.features(student)))
+            .collect()
+    }
+}
+
+/// The configuration of a single **[`Region`]**, such as its level caps and what is currently happening in it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RegionConfig {
+    /// The raw name of the region, such as `Jp` or `Global`, see [`RegionConfig::region`].
+    pub name: String,
+    pub student_max_level: Option<u32>,
+    pub weapon_max_level: Option<u32>,
+    pub bond_max_level: Option<u32>,
+    #[serde(default)]
+    pub equipment_max_level: Vec<u32>,
+    pub campaign_max: Option<u32>,
+    /// The ids of the events that have been released in the region.
+    #[serde(default)]
+    pub events: Vec<u32>,
+    #[serde(default)]
+    pub current_gacha: Vec<GachaBanner>,
+    #[serde(default)]
+    pub current_events: Vec<CurrentEvent>,
+    #[serde(default)]
+    pub current_raid: Vec<CurrentRaid>,
+    /// Everything else of the region that is not represented yet.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl RegionConfig {
+    /// Gets the **[`Region`]** of the configuration, if its name is known.
+    pub fn region(&self) -> Option<Region> {
+        match self.name.to_lowercase().as_str() {
+            "jp" | "japan" => Some(Region::Japan),
+            "global" | "gl" => Some(Region::Global),
+            "cn" | "china" => Some(Region::China),
+            _ => None,
+        }
+    }
+
+    /// Gets the **[`GachaBanners`][`GachaBanner`]** that are active at the given instant.
+    pub fn banners_at(&self, at: DateTime<Utc>) -> Vec<&GachaBanner> {
+        self.current_gacha
+            .iter()
+            .filter(|banner| banner.is_active(at))
+            .collect()
+    }
+
+    /// Gets the **[`CurrentEvents`][`CurrentEvent`]** that are active at the given instant.
+    pub fn events_at(&self, at: DateTime<Utc>) -> Vec<&CurrentEvent> {
+        self.current_events
+            .iter()
+            .filter(|event| event.is_active(at))
+            .collect()
+    }
+
+    /// Gets the **[`CurrentRaids`][`CurrentRaid`]** that are active at the given instant.
+    pub fn raids_at(&self, at: DateTime<Utc>) -> Vec<&CurrentRaid> {
+        self.current_raid
+            .iter()
+            .filter(|raid| raid.is_active(at))
+            .collect()
+    }
+
+    /// The next daily reset of the server of the region after the given instant, see [`Region::next_daily_reset`].
+    pub fn next_daily_reset(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.region().map(|region| region.next_daily_reset(at))
+    }
+}
+
+/// **A gacha banner**, on which some students have a raised rate.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GachaBanner {
+    /// The ids of the **[`Students`][`Student`]** of the banner.
+    #[serde(alias = "Characters", default)]
+    pub characters: Vec<u32>,
+    #[serde(alias = "Start", with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(alias = "End", with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+}
+
+impl GachaBanner {
+    /// Whether the banner is active at the given instant.
+    pub fn is_active(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// Whether the given **[`Student`]** is on the banner.
+    pub fn features(&self, student: &Student) -> bool {
+        self.characters.contains(&student.id.to_u32())
+    }
+
+    /// Gets the **[`Students`][`Student`]** out of the given ones that are on the banner.
+    pub fn students<'a>(&self, students: &'a [Student]) -> Vec<&'a Student> {
+        students
+            .iter()
+            .filter(|student| self.features(student))
+            .collect()
+    }
+}
+
+/// **An event that is currently scheduled** in a region.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentEvent {
+    /// The id of the event, which is also the key of its name in [`crate::types::Localization::event_name`].
+    #[serde(alias = "Event")]
+    pub event: u32,
+    #[serde(alias = "Start", with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(alias = "End", with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+}
+
+impl CurrentEvent {
+    /// Whether the event is active at the given instant.
+    pub fn is_active(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+}
+
+/// **A raid that is currently scheduled** in a region, such as a Total Assault or a Joint Firing Drill.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentRaid {
+    /// The kind of the raid, such as `Raid`, `EliminateRaid` or `TimeAttack`.
+    #[serde(rename = "type", alias = "Type")]
+    pub kind: String,
+    /// The id of the raid, in the list of its kind in **[`RaidData`][`crate::types::RaidData`]**.
+    #[serde(alias = "Raid")]
+    pub raid: u32,
+    #[serde(alias = "Terrain", default)]
+    terrain: Option<String>,
+    #[serde(alias = "Start", with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(alias = "End", with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+}
+
+impl CurrentRaid {
+    /// Gets the **[`Terrain`]** of the raid, if it has one.
+    pub fn terrain(&self) -> Option<Terrain> {
+        self.terrain
+            .as_ref()
+            .map(|terrain| Terrain::from_str(terrain).unwrap_or(Terrain::Unknown(terrain.clone())))
+    }
+
+    /// Whether the raid is active at the given instant.
+    pub fn is_active(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+}
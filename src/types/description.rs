@@ -0,0 +1,160 @@
+//! Contains the [`DescriptionRenderer`], which renders the descriptions of skills into text.
+
+use strum_macros::{Display, EnumIter};
+
+use super::Localization;
+
+/// How a **[`DescriptionRenderer`]** renders a description.
+#[derive(Debug, Default, Display, EnumIter, PartialEq, Eq, Clone, Copy)]
+pub enum RenderMode {
+    /// Plain text, without any styling.
+    #[default]
+    Plain,
+    /// Text for terminals, where values and buffs are styled with ANSI escape codes.
+    Ansi,
+    /// HTML, where values and buffs are in a `<span>` with a class, such as `skill-value` or `skill-buff`, and the rest is escaped.
+    Html,
+}
+
+/**
+    **Renders the description of a skill**, filling in the values of its parameters, such as `<?1>`,
+    and resolving its tags, such as `<b:AttackPower>` or `<c:Stunned>`, into the names of buffs, debuffs, crowd control and special states.
+
+    The names are taken from a **[`Localization`]** if one is given, and otherwise are the raw names of the tags, e.g. `AttackPower`.
+    A tag that is not known is left as it is, though a parameter that is missing, or that has no value at the index,
+    makes the rendering return [`None`] instead of a partially filled in description.
+
+    # Examples
+    ```
+    use blue_archive::{
+        types::description::{DescriptionRenderer, RenderMode},
+        Language, StudentFetcher,
+    };
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let fetcher = StudentFetcher::new(Language::English).await?;
+        let localization = blue_archive::fetch_localization(Language::English).await?;
+        let renderer = DescriptionRenderer::new(RenderMode::Ansi).localization(&localization);
+
+        let aru = fetcher.get_student_by_name("Aru").unwrap();
+        for skill in &aru.skills {
+            if let Some(description) = skill.render_description_with(4, &renderer) {
+                println!("{description}");
+            }
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DescriptionRenderer<'a> {
+    mode: RenderMode,
+    localization: Option<&'a Localization>,
+}
+
+impl<'a> DescriptionRenderer<'a> {
+    /// Creates a **[`DescriptionRenderer`]** that renders in a **[`RenderMode`]**, without a **[`Localization`]**.
+    pub fn new(mode: RenderMode) -> Self {
+        Self {
+            mode,
+            localization: None,
+        }
+    }
+
+    /// Sets the **[`Localization`]** that the names of the tags are taken from.
+    pub fn localization(mut self, localization: &'a Localization) -> Self {
+        self.localization = Some(localization);
+        self
+    }
+
+    /**
+        Renders a description with the values of its parameters at an index, such as a level or difficulty, where `0` is the first.
+
+        Returns [`None`] if a placeholder of the description, such as `<?1>`, has no value at `index`.
+        The index is not checked against the amount of levels, so a description without placeholders renders at any index.
+    */
+    pub fn render(
+        &self,
+        description: &str,
+        parameters: &[Vec<String>],
+        index: usize,
+    ) -> Option<String> {
+        let mut rendered = String::with_capacity(description.len());
+        let mut rest = description;
+        while let Some(start) = rest.find('<') {
+            self.push_text(&mut rendered, &rest[..start]);
+            let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+                rest = &rest[start..];
+                break;
+            };
+            let tag = &rest[start + 1..end];
+            let styled = match tag.strip_prefix('?') {
+                Some(number) => Some(self.styled(
+                    parameter_value(parameters, number, index)?,
+                    "skill-value",
+                    "1",
+                )),
+                None => self.render_tag(tag),
+            };
+            match styled {
+                Some(styled) => rendered.push_str(&styled),
+                None => self.push_text(&mut rendered, &rest[start..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        self.push_text(&mut rendered, rest);
+        Some(rendered)
+    }
+
+    /// Renders the inside of a tag, such as `b:AttackPower`, or [`None`] if it is not known.
+    fn render_tag(&self, tag: &str) -> Option<String> {
+        let (kind, key) = tag.split_once(':')?;
+        let (prefix, class, color) = match kind {
+            "b" => ("Buff_", "skill-buff", "34"),
+            "d" => ("Debuff_", "skill-debuff", "31"),
+            "c" => ("CC_", "skill-cc", "35"),
+            "s" => ("Special_", "skill-special", "32"),
+            _ => return None,
+        };
+        let name = key.strip_prefix(prefix).unwrap_or(key);
+        let name = self
+            .localization
+            .and_then(|localization| localization.buff_name.get(&format!("{prefix}{name}")))
+            .map_or(name, String::as_str);
+        Some(self.styled(name, class, color))
+    }
+
+    fn styled(&self, text: &str, class: &str, color: &str) -> String {
+        match self.mode {
+            RenderMode::Plain => text.to_string(),
+            RenderMode::Ansi => format!("\x1b[{color}m{text}\x1b[0m"),
+            RenderMode::Html => format!(
+                "<span class=\"{class}\">{}</span>",
+                html_escape::encode_text(text)
+            ),
+        }
+    }
+
+    fn push_text(&self, rendered: &mut String, text: &str) {
+        match self.mode {
+            RenderMode::Html => {
+                rendered.push_str(&html_escape::encode_text(text).replace('\n', "<br>"))
+            }
+            _ => rendered.push_str(text),
+        }
+    }
+}
+
+/// The value of a parameter by its number, such as `1` of `<?1>`, at an index, if it has one.
+fn parameter_value<'p>(
+    parameters: &'p [Vec<String>],
+    number: &str,
+    index: usize,
+) -> Option<&'p str> {
+    let number = number.parse::<usize>().ok()?;
+    parameters
+        .get(number.checked_sub(1)?)?
+        .get(index)
+        .map(String::as_str)
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
@@ -71,3 +71,65 @@ impl Enemy {
             .unwrap_or(WeaponType::Unknown(self.weapon_type.clone()))
     }
 }
+
+/**
+    The **[`Enemies`][`Enemy`]** of each difficulty of a raid, resolved from their raw ids.
+
+    Created by [`Raid::resolve_enemies`][`crate::types::raids::Raid::resolve_enemies`]
+    or [`WorldRaid::resolve_enemies`][`crate::types::raids::WorldRaid::resolve_enemies`].
+*/
+#[derive(Debug, Clone)]
+pub struct EnemyTiers<'a> {
+    /// The enemies of each difficulty, from the easiest to the hardest, without the ones that could not be resolved.
+    pub tiers: Vec<Vec<&'a Enemy>>,
+    /// The ids that could not be resolved into an **[`Enemy`]**.
+    pub unresolved: Vec<UnresolvedEnemy>,
+}
+
+impl<'a> EnemyTiers<'a> {
+    /// Resolves the ids of each difficulty out of the given enemies.
+    pub(crate) fn resolve(enemy_list: &[Vec<u32>], enemies: &'a [Enemy]) -> Self {
+        let by_id = enemies
+            .iter()
+            .map(|enemy| (enemy.id.to_u32(), enemy))
+            .collect::<HashMap<_, _>>();
+        let mut unresolved = vec![];
+        let tiers = enemy_list
+            .iter()
+            .enumerate()
+            .map(|(difficulty, ids)| {
+                ids.iter()
+                    .filter_map(|id| {
+                        let enemy = by_id.get(id).copied();
+                        if enemy.is_none() {
+                            unresolved.push(UnresolvedEnemy {
+                                difficulty,
+                                id: *id,
+                            });
+                        }
+                        enemy
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { tiers, unresolved }
+    }
+
+    /// The enemies of a difficulty, where `0` is the easiest.
+    pub fn tier(&self, difficulty: usize) -> &[&'a Enemy] {
+        self.tiers.get(difficulty).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether every id was resolved into an **[`Enemy`]**.
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+/// An id of an **[`Enemy`]** at a difficulty that could not be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnresolvedEnemy {
+    /// The difficulty that the id is at, where `0` is the easiest.
+    pub difficulty: usize,
+    pub id: u32,
+}
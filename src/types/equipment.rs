@@ -41,7 +41,7 @@ pub struct Shop {
     pub cost_amount: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, EnumString)]
 pub enum EquipmentCategory {
     Shoes,
     Hat,
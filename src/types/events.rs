@@ -0,0 +1,198 @@
+//! Contains the [`Event`] structure and its respective structures.
+
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{serialization, Region};
+
+use super::{item::ItemIndex, stages::RewardType, Item, Released, Student};
+
+/**
+    **An event of the game**, with its currencies, shop, point rewards and bonus students.
+
+    # Examples
+    ```
+    use blue_archive::Language;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let events = blue_archive::fetch_all_events(Language::English).await?;
+        let students = blue_archive::fetch_all_students(Language::English).await?;
+        if let Some(event) = events.iter().find(|event| event.id == 801) {
+            for student in event.bonus_students(&students) {
+                println!("{} has a bonus in {}", student.full_name_last(), event.name);
+            }
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Event {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    /// The **[`Released`]** status of the event, see [`Event::is_released_in`].
+    #[serde(alias = "IsReleased")]
+    pub released: Released,
+    /// The ids of the **[`Items`][`Item`]** that are the currencies of the event.
+    #[serde(alias = "EventCurrency", default)]
+    pub currencies: Vec<u32>,
+    /// The goods of the shop of the event, see **[`ShopItem`]**.
+    #[serde(default)]
+    pub shop: Vec<ShopItem>,
+    /// The rewards for collecting points in the event, see **[`PointReward`]**.
+    #[serde(default)]
+    pub point_rewards: Vec<PointReward>,
+    /// The bonuses that students give to the currencies of the event, see **[`EventBonus`]**.
+    #[serde(alias = "BonusStudents", default)]
+    pub bonus: Vec<EventBonus>,
+}
+
+impl Event {
+    /// Whether the event has been released in the given **[`Region`]**.
+    pub fn is_released_in(&self, region: Region) -> bool {
+        self.released.in_region(region)
+    }
+
+    /// Gets the **[`Items`][`Item`]** of the currencies of the event out of an **[`ItemIndex`]**.
+    pub fn currency_items<'a>(&self, items: &'a ItemIndex) -> Vec<&'a Item> {
+        self.currencies
+            .iter()
+            .filter_map(|currency| items.get(*currency))
+            .collect()
+    }
+
+    /// Gets the goods of the shop that are bought with the given currency.
+    pub fn shop_for(&self, currency_id: u32) -> Vec<&ShopItem> {
+        self.shop
+            .iter()
+            .filter(|item| item.cost_id == currency_id)
+            .collect()
+    }
+
+    /// Gets the ids of the students that give a bonus in the event, without duplicates.
+    pub fn bonus_student_ids(&self) -> Vec<u32> {
+        let mut ids = self
+            .bonus
+            .iter()
+            .map(|bonus| bonus.student_id)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Gets the **[`Students`][`Student`]** out of the given ones that give a bonus in the event.
+    pub fn bonus_students<'a>(&self, students: &'a [Student]) -> Vec<&'a Student> {
+        let ids = self.bonus_student_ids();
+        students
+            .iter()
+            .filter(|student| ids.binary_search(&student.id.to_u32()).is_ok())
+            .collect()
+    }
+
+    /// Gets the bonuses that a student gives in the event, one for each currency.
+    pub fn bonus_of(&self, student_id: u32) -> Vec<&EventBonus> {
+        self.bonus
+            .iter()
+            .filter(|bonus| bonus.student_id == student_id)
+            .collect()
+    }
+
+    /**
+        Sums up the bonuses that a roster of students gives to each currency of the event, in ten-thousandths.
+
+        The ids are the raw ids of the students, such as from [`ID::to_u32`][`crate::types::ID::to_u32`],
+        and an id that is in the roster more than once only counts once.
+        Currencies that none of the roster gives a bonus to are left out.
+    */
+    pub fn roster_bonus(&self, student_ids: &[u32]) -> HashMap<u32, u32> {
+        let mut roster = student_ids.to_vec();
+        roster.sort_unstable();
+        roster.dedup();
+
+        let mut totals = HashMap::new();
+        for bonus in &self.bonus {
+            if roster.binary_search(&bonus.student_id).is_ok() {
+                *totals.entry(bonus.currency_id).or_insert(0) += bonus.rate;
+            }
+        }
+        totals
+    }
+}
+
+/// Goods in the shop of an **[`Event`]**, and what they cost.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShopItem {
+    pub id: u32,
+    /// What is bought.
+    pub goods: EventReward,
+    /// The id of the **[`Item`]** that the goods are bought with, which is one of [`Event::currencies`].
+    pub cost_id: u32,
+    pub cost_amount: u32,
+    /// How many times the goods can be bought, where [`None`] is without a limit.
+    #[serde(default)]
+    pub stock: Option<u32>,
+}
+
+/// The rewards for reaching an amount of points in an **[`Event`]**.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PointReward {
+    pub points: u32,
+    #[serde(default)]
+    pub rewards: Vec<EventReward>,
+}
+
+/// An amount of something that is rewarded or bought in an **[`Event`]**.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventReward {
+    #[serde(rename = "Type")]
+    reward_type: String,
+    pub id: u32,
+    #[serde(default = "serialization::default_amount")]
+    pub amount: u32,
+}
+
+impl EventReward {
+    /// Gets the **[`RewardType`]** of the reward.
+    pub fn reward_type(&self) -> RewardType {
+        RewardType::from_str(&self.reward_type)
+            .unwrap_or(RewardType::Unknown(self.reward_type.clone()))
+    }
+
+    /// Gets the **[`Item`]** of the reward out of an **[`ItemIndex`]**, if the reward is one.
+    pub fn item<'a>(&self, items: &'a ItemIndex) -> Option<&'a Item> {
+        match self.reward_type() {
+            RewardType::Item => items.get(self.id),
+            _ => None,
+        }
+    }
+}
+
+/// The bonus that a student gives to a currency of an **[`Event`]** when they are in the formation.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventBonus {
+    /// The raw id of the **[`Student`]**.
+    #[serde(alias = "CharacterId")]
+    pub student_id: u32,
+    /// The id of the **[`Item`]** of the currency, which is one of [`Event::currencies`].
+    #[serde(alias = "Currency")]
+    pub currency_id: u32,
+    /// The bonus in ten-thousandths, like the data, where `1500` is 15%, see [`EventBonus::percentage`].
+    #[serde(alias = "Bonus")]
+    pub rate: u32,
+}
+
+impl EventBonus {
+    /// The bonus as a percentage, where `15.0` is 15%.
+    pub fn percentage(&self) -> f64 {
+        self.rate as f64 / 100.0
+    }
+}
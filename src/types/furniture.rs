@@ -0,0 +1,190 @@
+//! Contains the [`Furniture`] structure and its respective structures.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+use crate::serialization;
+
+use super::{Rarity, Released, Student, ID};
+
+/**
+    **A piece of furniture** that can be placed in the cafe, which raises its comfort.
+
+    Some furniture has interaction animations with certain students, which can be looked up with [`Furniture::interaction_students`],
+    and the other way around with [`Student::interaction_furniture`].
+
+    # Examples
+    ```
+    use blue_archive::Language;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let students = blue_archive::fetch_all_students(Language::English).await?;
+        let furniture = blue_archive::fetch_all_furniture(Language::English).await?;
+        for piece in furniture.iter().filter(|piece| piece.has_interaction()) {
+            let names = piece
+                .interaction_students(&students)
+                .iter()
+                .map(|student| student.full_name_last())
+                .collect::<Vec<_>>();
+            println!("{}: {}", piece.name, names.join(", "));
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Furniture {
+    /// The **[`ID`]** of the furniture.
+    #[serde(alias = "Id")]
+    pub id: ID,
+    /// The **[`Released`]** status of the furniture.
+    #[serde(alias = "IsReleased")]
+    pub released: Released,
+    pub name: String,
+    #[serde(
+        alias = "Desc",
+        default,
+        deserialize_with = "serialization::deserialize_html_encoded_string"
+    )]
+    pub description: String,
+    category: String,
+    sub_category: String,
+    pub rarity: Rarity,
+    /// The comfort that the furniture adds to the cafe.
+    #[serde(default)]
+    pub comfort_bonus: u32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The set that the furniture belongs to, if any.
+    #[serde(alias = "SetGroupId", default)]
+    pub set_group: Option<u32>,
+    icon: String,
+    #[serde(
+        alias = "Interaction",
+        default,
+        deserialize_with = "serialization::deserialize_flattened_ids"
+    )]
+    interaction_student_ids: Vec<ID>,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
+}
+
+impl Furniture {
+    /// Gets the **[`FurnitureCategory`]** of the furniture.
+    pub fn category(&self) -> FurnitureCategory {
+        FurnitureCategory::from_str(&self.category)
+            .unwrap_or(FurnitureCategory::Unknown(self.category.clone()))
+    }
+
+    /// Gets the **[`FurnitureSubCategory`]** of the furniture.
+    pub fn sub_category(&self) -> FurnitureSubCategory {
+        FurnitureSubCategory::from_str(&self.sub_category)
+            .unwrap_or(FurnitureSubCategory::Unknown(self.sub_category.clone()))
+    }
+
+    /// Returns the url of the furniture icon.
+    pub fn icon_url(&self) -> String {
+        format!("{}/furniture/{}.webp", self.image_uri, self.icon)
+    }
+
+    /// The **[`IDs`][`ID`]** of the students that have an interaction animation with the furniture.
+    pub fn interaction_student_ids(&self) -> &[ID] {
+        &self.interaction_student_ids
+    }
+
+    /// Whether any student has an interaction animation with the furniture.
+    pub fn has_interaction(&self) -> bool {
+        !self.interaction_student_ids.is_empty()
+    }
+
+    /// Whether the given **[`Student`]** has an interaction animation with the furniture.
+    pub fn interacts_with(&self, student: &Student) -> bool {
+        self.interaction_student_ids.contains(&student.id)
+    }
+
+    /// Gets the **[`Students`][`Student`]** out of the given ones that have an interaction animation with the furniture.
+    pub fn interaction_students<'a>(&self, students: &'a [Student]) -> Vec<&'a Student> {
+        students
+            .iter()
+            .filter(|student| self.interacts_with(student))
+            .collect()
+    }
+
+    /// Gets the furniture out of the given ones that belongs to the same set as this one, including itself.
+    pub fn set_pieces<'a>(&self, furniture: &'a [Furniture]) -> Vec<&'a Furniture> {
+        match self.set_group {
+            Some(set_group) => furniture
+                .iter()
+                .filter(|piece| piece.set_group == Some(set_group))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Builds the icon url of this furniture relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, image_uri: &str) {
+        self.image_uri = image_uri.to_string();
+    }
+}
+
+impl Student {
+    /// Gets the **[`Furniture`]** out of the given ones that the student has an interaction animation with.
+    pub fn interaction_furniture<'a>(&self, furniture: &'a [Furniture]) -> Vec<&'a Furniture> {
+        furniture
+            .iter()
+            .filter(|piece| piece.interacts_with(self))
+            .collect()
+    }
+}
+
+/**
+    The category of a **[`Furniture`]**.
+
+    In the case that a category in the data is not present on the wrapper,
+    a [`FurnitureCategory::Unknown(String)`] is returned to represent the unknown category with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum FurnitureCategory {
+    /// Furniture that is placed on the floor, such as tables and chairs.
+    #[strum(serialize = "Furnitures")]
+    Furniture,
+    /// Decorations that are placed on the floor or the walls.
+    #[strum(serialize = "Decorations")]
+    Decoration,
+    /// The wallpaper, floor and background of the cafe.
+    #[strum(serialize = "Interiors")]
+    Interior,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
+/**
+    The sub-category of a **[`Furniture`]**, which is more specific than its **[`FurnitureCategory`]**.
+
+    In the case that a sub-category in the data is not present on the wrapper,
+    a [`FurnitureSubCategory::Unknown(String)`] is returned to represent the unknown sub-category with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum FurnitureSubCategory {
+    Table,
+    Chair,
+    Closet,
+    Bed,
+    /// Appliances, such as televisions and refrigerators.
+    HomeAppliance,
+    /// Props, such as plants and lamps.
+    Prop,
+    /// Furniture that does not fit any other sub-category.
+    FurnitureEtc,
+    WallDecoration,
+    FloorDecoration,
+    Floor,
+    Wallpaper,
+    Background,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
@@ -0,0 +1,243 @@
+//! Contains the [`Item`] structure and its respective structures.
+
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+use crate::serialization;
+
+use super::{equipment::Equipment, students::student::Gear, Rarity, Released, Student, ID};
+
+/**
+    **An item of the game**, such as a tech note, an artifact or a gift.
+
+    Much of the data refers to items by their raw **[`ID`]**, such as the skill materials of a **[`Student`]**,
+    which can be resolved with an **[`ItemIndex`]**.
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Item {
+    /// The **[`ID`]** of the item.
+    #[serde(alias = "Id")]
+    pub id: ID,
+    /// The **[`Released`]** status of the item.
+    #[serde(alias = "IsReleased")]
+    pub released: Released,
+    pub name: String,
+    #[serde(
+        alias = "Desc",
+        default,
+        deserialize_with = "serialization::deserialize_html_encoded_string"
+    )]
+    pub description: String,
+    category: String,
+    #[serde(default)]
+    sub_category: Option<String>,
+    pub rarity: Rarity,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    icon: String,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
+}
+
+impl Item {
+    /// Gets the **[`ItemCategory`]** of the item.
+    pub fn category(&self) -> ItemCategory {
+        ItemCategory::from_str(&self.category)
+            .unwrap_or(ItemCategory::Unknown(self.category.clone()))
+    }
+
+    /// Gets the **[`ItemSubCategory`]** of the item, if it has one.
+    pub fn sub_category(&self) -> Option<ItemSubCategory> {
+        self.sub_category.as_ref().map(|sub_category| {
+            ItemSubCategory::from_str(sub_category)
+                .unwrap_or(ItemSubCategory::Unknown(sub_category.clone()))
+        })
+    }
+
+    /// Returns the url of the item icon.
+    pub fn icon_url(&self) -> String {
+        format!("{}/item/{}.webp", self.image_uri, self.icon)
+    }
+
+    /// Builds the icon url of this item relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, image_uri: &str) {
+        self.image_uri = image_uri.to_string();
+    }
+}
+
+/**
+    The category of an **[`Item`]**.
+
+    In the case that a category in the data is not present on the wrapper,
+    a [`ItemCategory::Unknown(String)`] is returned to represent the unknown category with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum ItemCategory {
+    /// Coins that are exchanged in shops.
+    Coin,
+    /// Activity reports, which level up students.
+    CharacterExpGrowth,
+    /// Eleph, which raise the star grade of students.
+    SecretStone,
+    /// Materials for skills, gear and equipment, such as tech notes, tactical training blu-rays and artifacts.
+    Material,
+    /// Gifts, which raise the bond of students.
+    Favor,
+    /// Items that are used up, such as boxes and tickets.
+    Consumable,
+    /// Items that are only collected.
+    Collectible,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
+/**
+    The sub-category of an **[`Item`]**, which mostly tells apart the kinds of [`ItemCategory::Material`].
+
+    In the case that a sub-category in the data is not present on the wrapper,
+    a [`ItemSubCategory::Unknown(String)`] is returned to represent the unknown sub-category with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum ItemSubCategory {
+    /// Out-of-place artifacts (OOParts), which are used for skills.
+    Artifact,
+    /// Tactical training blu-rays (BD), which are used for EX skills.
+    #[strum(serialize = "CDItem")]
+    Bluray,
+    /// Tech notes, which are used for skills.
+    #[strum(serialize = "BookItem")]
+    TechNote,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
+/// An amount of an item referred to by its raw `id`, alongside the **[`Item`]** it resolved to, if any.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ItemAmount<'a> {
+    pub id: u32,
+    pub amount: u32,
+    pub item: Option<&'a Item>,
+}
+
+/**
+    **Looks up [`Items`][`Item`] by their [`ID`]**, which makes the raw ids in the rest of the data usable.
+
+    # Examples
+    ```
+    use blue_archive::{types::item::ItemIndex, Language};
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let items = ItemIndex::new(blue_archive::fetch_all_items(Language::English).await?);
+        let students = blue_archive::fetch_all_students(Language::English).await?;
+        for (level, materials) in items.skill_materials(&students[0]).iter().enumerate() {
+            let names = materials
+                .iter()
+                .filter_map(|material| material.item.map(|item| format!("{} x{}", item.name, material.amount)))
+                .collect::<Vec<_>>();
+            println!("Level {}: {}", level + 2, names.join(", "));
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ItemIndex {
+    items: HashMap<u32, Item>,
+}
+
+impl ItemIndex {
+    /// Indexes the given **[`Items`][`Item`]** by their **[`ID`]**.
+    pub fn new(items: impl IntoIterator<Item = Item>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|item| (item.id.to_u32(), item))
+                .collect(),
+        }
+    }
+
+    /// Gets an **[`Item`]** by its raw id.
+    pub fn get(&self, id: impl Into<u32>) -> Option<&Item> {
+        self.items.get(&id.into())
+    }
+
+    /// The amount of indexed items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there are no indexed items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterates over all of the indexed items, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.items.values()
+    }
+
+    /// Resolves the materials of each skill level of a **[`Student`]**, from [`Student::skill_material`] and its amounts.
+    pub fn skill_materials(&self, student: &Student) -> Vec<Vec<ItemAmount<'_>>> {
+        self.resolve_levels(&student.skill_material, &student.skill_material_amount)
+    }
+
+    /// Resolves the materials of each EX skill level of a **[`Student`]**, from [`Student::skill_ex_material`] and its amounts.
+    pub fn skill_ex_materials(&self, student: &Student) -> Vec<Vec<ItemAmount<'_>>> {
+        self.resolve_levels(
+            &student.skill_ex_material,
+            &student.skill_ex_material_amount,
+        )
+    }
+
+    /// Resolves the materials of each tier of a **[`Gear`]**, from [`Gear::tier_up_material`] and its amounts.
+    pub fn tier_up_materials(&self, gear: &Gear) -> Vec<Vec<ItemAmount<'_>>> {
+        self.resolve_levels(&gear.tier_up_material, &gear.tier_up_material_amount)
+    }
+
+    /// Resolves the recipe of an **[`Equipment`]**, in which each entry of [`Equipment::recipe`] is an id and its amount.
+    pub fn recipe(&self, equipment: &Equipment) -> Vec<ItemAmount<'_>> {
+        equipment
+            .recipe
+            .iter()
+            .flatten()
+            .filter_map(|entry| match entry[..] {
+                [id, amount, ..] => Some(self.resolve(id, amount)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn resolve(&self, id: u32, amount: u32) -> ItemAmount<'_> {
+        ItemAmount {
+            id,
+            amount,
+            item: self.get(id),
+        }
+    }
+
+    fn resolve_levels<I: Copy + Into<u32>, A: Copy + Into<u32>>(
+        &self,
+        ids: &[Vec<I>],
+        amounts: &[Vec<A>],
+    ) -> Vec<Vec<ItemAmount<'_>>> {
+        ids.iter()
+            .zip(amounts)
+            .map(|(ids, amounts)| {
+                ids.iter()
+                    .zip(amounts)
+                    .map(|(id, amount)| self.resolve((*id).into(), (*amount).into()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Item> for ItemIndex {
+    fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
@@ -0,0 +1,173 @@
+//! Contains the [`Localization`] structure, which holds the localized strings of the game.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{serialization, Club, School};
+
+use super::{Effect, Student};
+
+/**
+    **The localized strings of the game**, such as the names of stats, buffs and schools in a [`crate::Language`].
+
+    Each field maps a raw key of the data to its localized text, e.g. `AttackPower` to `ATK`.
+    The helpers, such as [`Localization::stat_name`], translate raw keys into text, falling back to the raw key if it is missing.
+
+    # Examples
+    ```
+    use blue_archive::Language;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let localization = blue_archive::fetch_localization(Language::English).await?;
+        println!("{}", localization.stat_name("AttackPower_Coefficient"));
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Localization {
+    /// The names of stats, keyed by their base name, e.g. `AttackPower`.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub stat_name: HashMap<String, String>,
+    /// The names of buffs, debuffs and crowd control, e.g. `Buff_AttackPower` or `CC_Stunned`.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub buff_name: HashMap<String, String>,
+    /// The longer names of buffs, debuffs and crowd control.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub buff_name_long: HashMap<String, String>,
+    /// The descriptions of buffs, debuffs and crowd control.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub buff_tooltip: HashMap<String, String>,
+    /// The strings of the user interface of ``SchaleDB``.
+    #[serde(
+        default,
+        rename = "ui",
+        deserialize_with = "serialization::deserialize_string_map"
+    )]
+    pub ui: HashMap<String, String>,
+    /// The short names of schools, keyed by their raw name, e.g. `RedWinter`.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub school: HashMap<String, String>,
+    /// The full names of schools, keyed by their raw name, e.g. `RedWinter`.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub school_long: HashMap<String, String>,
+    /// The names of clubs, keyed by their raw name, e.g. `Kohshinjo68`.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub club: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub squad_type: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub tactic_role: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub armor_type: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub bullet_type: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub weapon_type: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub equipment_category: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub item_category: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub furniture_category: HashMap<String, String>,
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub furniture_sub_category: HashMap<String, String>,
+    /// The names of events, keyed by their ID.
+    #[serde(default, deserialize_with = "serialization::deserialize_string_map")]
+    pub event_name: HashMap<String, String>,
+    /// Every other category of strings that is not represented yet.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl Localization {
+    /**
+        Translates a raw stat into its name, such as `AttackPower_Coefficient` or `AttackPower` into `ATK`.
+
+        The suffix of a stat, such as `_Base` or `_Coefficient`, only decides how its value is applied, and is not part of the name.
+    */
+    pub fn stat_name(&self, stat: &str) -> String {
+        self.stat_name
+            .get(stat)
+            .or_else(|| self.stat_name.get(base_stat(stat)))
+            .cloned()
+            .unwrap_or_else(|| stat.to_string())
+    }
+
+    /// Translates a raw buff, such as `Buff_AttackPower` or `CC_Stunned`, into its name.
+    pub fn buff_name(&self, buff: &str) -> String {
+        self.buff_name
+            .get(buff)
+            .cloned()
+            .unwrap_or_else(|| buff.to_string())
+    }
+
+    /// Translates a raw stat into the name of the buff (or if `debuff`, the debuff) of it, such as `AttackPower_Coefficient` into `ATK Up`.
+    pub fn stat_buff_name(&self, stat: &str, debuff: bool) -> String {
+        let kind = match debuff {
+            true => "Debuff",
+            false => "Buff",
+        };
+        self.buff_name
+            .get(&format!("{kind}_{}", base_stat(stat)))
+            .cloned()
+            .unwrap_or_else(|| self.stat_name(stat))
+    }
+
+    /**
+        Translates what an **[`Effect`]** does into text, such as the stat that it buffs, or the crowd control it inflicts.
+
+        Returns [`None`] for effects which are not named, such as damage and healing.
+    */
+    pub fn effect_name(&self, effect: &Effect) -> Option<String> {
+        match effect {
+            Effect::BuffSelf {
+                icon: Some(icon), ..
+            } => Some(self.buff_name(icon)),
+            Effect::BuffSelf { stat, .. }
+            | Effect::BuffTarget { stat, .. }
+            | Effect::BuffAlly { stat, .. } => Some(self.stat_name(stat)),
+            Effect::CrowdControl { icon, .. }
+            | Effect::DMGDot { icon, .. }
+            | Effect::DMGByHit { icon, .. } => Some(self.buff_name(icon)),
+            _ => None,
+        }
+    }
+
+    /// Gets a string of the user interface, such as `student`.
+    pub fn ui(&self, key: &str) -> Option<&str> {
+        self.ui.get(key).map(String::as_str)
+    }
+
+    /// Translates a **[`School`]** into its full name, falling back to [`School::full_name`].
+    pub fn school_name(&self, school: &School) -> String {
+        let key = match school {
+            School::Unknown(school) => school.clone(),
+            school => school.to_string(),
+        };
+        self.school_long
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| school.full_name())
+    }
+
+    /// Translates the club of a **[`Student`]** into its name, falling back to the name of [`Student::club`].
+    pub fn club_name(&self, student: &Student) -> String {
+        self.club
+            .get(&student.club)
+            .cloned()
+            .unwrap_or_else(|| match student.club() {
+                Club::Unknown(club) => club,
+                club => club.to_string(),
+            })
+    }
+}
+
+/// The base name of a stat, without the suffix of how it is applied, e.g. `AttackPower` of `AttackPower_Coefficient`.
+fn base_stat(stat: &str) -> &str {
+    stat.split_once('_').map_or(stat, |(base, _)| base)
+}
@@ -1,18 +1,33 @@
 //! Contains many structures that make up the deserialized data.
 
+pub mod config;
 pub mod currency;
+pub mod description;
 pub mod enemy;
 pub mod equipment;
+pub mod events;
+pub mod furniture;
+pub mod item;
+pub mod localization;
 pub mod raids;
+pub mod stages;
 pub mod students;
 pub mod summons;
+pub mod voice;
 
+pub use config::Config;
+pub use events::Event;
+pub use furniture::Furniture;
+pub use item::Item;
+pub use localization::Localization;
 pub use raids::RaidData;
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
+pub use stages::StageData;
 use strum_macros::{Display, EnumString};
 pub use students::{Age, Released, Student};
 pub use summons::Summon;
+pub use voice::VoiceData;
 
 /// **A Blue Archive ID**.
 ///
@@ -290,7 +305,7 @@ pub struct Radius {
     radius: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, EnumString, Display)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, EnumString, Display)]
 pub enum Rarity {
     #[strum(to_string = "Normal")]
     N,
@@ -2,12 +2,21 @@
 
 use std::str::FromStr;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use strum_macros::{Display, EnumString};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
 
-use crate::serialization;
+use crate::{
+    serialization, Armor, BulletType, Position, Region, School, Squad, TacticalRole, Terrain,
+    WeaponType,
+};
 
-use super::{Effect, Released, ID};
+use super::{
+    description::DescriptionRenderer,
+    enemy::{Enemy, EnemyTiers},
+    Effect, Released, RestrictValue, Restriction, Student, ID,
+};
 
 /// Contains data including **[`Raids`][`Raid`]** and other kinds of information.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -15,10 +24,18 @@ use super::{Effect, Released, ID};
 pub struct RaidData {
     #[serde(alias = "Raid")]
     pub raids: Vec<Raid>,
-    // pub raid_seasons: Vec<RaidSeason>,
-    // pub time_attack: Vec<TimeAttack>,
-    // pub time_attack_rules: Vec<TimeAttackRule>,
-    pub world_raid: Vec<Raid>,
+    /// The **[`RaidSeasons`]** of each region, in the order of [`Region::Japan`], [`Region::Global`] and [`Region::China`].
+    #[serde(default)]
+    pub raid_seasons: Vec<RaidSeasons>,
+    /// The Joint Firing Drills, see **[`TimeAttack`]**.
+    #[serde(default)]
+    pub time_attack: Vec<TimeAttack>,
+    /// The rules that apply during Joint Firing Drills, see **[`TimeAttackRule`]**.
+    #[serde(default)]
+    pub time_attack_rules: Vec<TimeAttackRule>,
+    /// The World Raids, see **[`WorldRaid`]**.
+    #[serde(default)]
+    pub world_raid: Vec<WorldRaid>,
 }
 
 /// **A Blue Archive raid.**
@@ -38,7 +55,8 @@ pub struct Raid {
     pub bullet_type: String,
     pub bullet_type_insane: Option<String>,
     armor_type: String,
-    pub enemy_list: Vec<Vec<u32>>, // todo: might associate with ID's of enemies. Will need further lookup in order to deserialize.
+    /// The ids of the **[`Enemies`][`Enemy`]** at each difficulty, see [`Raid::resolve_enemies`].
+    pub enemy_list: Vec<Vec<u32>>,
     #[serde(alias = "RaidSkill")]
     pub skills: Vec<Skill>,
     pub exclude_normal_attack: Option<Vec<u32>>,
@@ -46,36 +64,670 @@ pub struct Raid {
     pub icon: Option<String>,
     #[serde(alias = "IconBG")]
     pub icon_bg: Option<String>,
-    pub difficulty_name: Option<Vec<String>>,
-    pub difficulty_max: Option<Vec<i8>>,
     pub profile: Option<String>,
-    #[serde(alias = "WorldBossHP")]
-    pub world_boss_hp: Option<u64>,
     pub level: Option<Vec<i32>>,
 }
 
+impl RaidData {
+    /// Builds the urls of the skills of every raid relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, image_uri: &str) {
+        let raid_skills = self.raids.iter_mut().flat_map(|raid| &mut raid.skills);
+        let world_raid_skills = self
+            .world_raid
+            .iter_mut()
+            .flat_map(|world_raid| &mut world_raid.skills);
+        for skill in raid_skills.chain(world_raid_skills) {
+            skill.set_image_uri(image_uri);
+        }
+    }
+
+    /// Gets the **[`RaidSeasons`]** of a **[`Region`]**.
+    pub fn seasons(&self, region: Region) -> Option<&RaidSeasons> {
+        self.raid_seasons.get(region.index())
+    }
+
+    /// Gets the **[`Raid`]** of a **[`RaidSeason`]**.
+    pub fn raid_of(&self, season: &RaidSeason) -> Option<&Raid> {
+        self.raids
+            .iter()
+            .find(|raid| raid.id.to_u32() == season.raid_id)
+    }
+
+    /// Gets the **[`TimeAttackRules`][`TimeAttackRule`]** of a **[`TimeAttack`]** at a difficulty, where `0` is the easiest.
+    pub fn rules_of(&self, time_attack: &TimeAttack, difficulty: usize) -> Vec<&TimeAttackRule> {
+        time_attack
+            .rule_ids(difficulty)
+            .into_iter()
+            .filter_map(|id| {
+                self.time_attack_rules
+                    .iter()
+                    .find(|rule| rule.id.to_u32() == id)
+            })
+            .collect()
+    }
+
+    /**
+        Gets the Total Assault **[`RaidSeason`]** of a **[`Region`]** that is ongoing at the given instant.
+
+        # Examples
+        ```
+        use blue_archive::{Language, Region};
+
+        #[tokio::main]
+        async fn main() -> anyhow::Result<()> {
+            let raid_data = blue_archive::fetch_raid_data(Language::English).await?;
+            match raid_data.current_season(Region::Global, chrono::Utc::now()) {
+                Some(season) => println!("Season {} is ongoing until {}", season.season, season.end),
+                None => println!("There is no ongoing season"),
+            }
+            Ok(())
+        }
+        ```
+    */
+    pub fn current_season(&self, region: Region, at: DateTime<Utc>) -> Option<&RaidSeason> {
+        self.seasons(region)?.current(at)
+    }
+
+    /// Gets the Total Assault **[`RaidSeason`]** of a **[`Region`]** that starts the soonest after the given instant.
+    pub fn next_season(&self, region: Region, at: DateTime<Utc>) -> Option<&RaidSeason> {
+        self.seasons(region)?.next(at)
+    }
+}
+
+/// The seasons of raids in a single **[`Region`]**.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RaidSeasons {
+    /// The seasons of Total Assault.
+    #[serde(default)]
+    pub seasons: Vec<RaidSeason>,
+    /// The seasons of Grand Assault, in which a raid is fought with each armor type.
+    #[serde(default)]
+    pub eliminate_seasons: Vec<RaidSeason>,
+}
+
+impl RaidSeasons {
+    /// Gets the Total Assault season that is ongoing at the given instant.
+    pub fn current(&self, at: DateTime<Utc>) -> Option<&RaidSeason> {
+        self.seasons.iter().find(|season| season.is_ongoing(at))
+    }
+
+    /// Gets the Total Assault season that starts the soonest after the given instant.
+    pub fn next(&self, at: DateTime<Utc>) -> Option<&RaidSeason> {
+        self.seasons
+            .iter()
+            .filter(|season| season.start > at)
+            .min_by_key(|season| season.start)
+    }
+}
+
+/// **A season of a [`Raid`]**, during which it can be fought in a region.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RaidSeason {
+    /// The number of the season.
+    pub season: u32,
+    /// The id of the **[`Raid`]** that is fought, see [`RaidData::raid_of`].
+    #[serde(alias = "RaidId")]
+    pub raid_id: u32,
+    terrain: String,
+    /// When the season starts.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    /// When the season ends.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+    #[serde(alias = "OpenDifficulty", default)]
+    opened_difficulties: Vec<DifficultyValue>,
+    /// The armor type of the raid in a Grand Assault season.
+    #[serde(default)]
+    armor_type: Option<String>,
+}
+
+impl RaidSeason {
+    /// Gets the **[`Terrain`]** that the raid is fought in during the season.
+    pub fn terrain(&self) -> Terrain {
+        Terrain::from_str(&self.terrain).unwrap_or(Terrain::Unknown(self.terrain.clone()))
+    }
+
+    /// Gets the **[`RaidDifficulties`][`RaidDifficulty`]** that are open during the season.
+    pub fn opened_difficulties(&self) -> Vec<RaidDifficulty> {
+        self.opened_difficulties
+            .iter()
+            .map(DifficultyValue::difficulty)
+            .collect()
+    }
+
+    /// Gets the **[`Armor`][`crate::Armor`]** of the raid during a Grand Assault season, if this is one.
+    pub fn armor(&self) -> Option<crate::Armor> {
+        self.armor_type.as_ref().map(|armor_type| {
+            crate::Armor::from_str(armor_type).unwrap_or(crate::Armor::Unknown(armor_type.clone()))
+        })
+    }
+
+    /// Whether the season is ongoing at the given instant.
+    pub fn is_ongoing(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+}
+
+/**
+    The difficulties of a **[`Raid`]**, from the easiest to the hardest.
+
+    In the case that a difficulty in the data is not present on the wrapper,
+    a [`RaidDifficulty::Unknown(String)`] is returned to represent the unknown difficulty with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum RaidDifficulty {
+    Normal,
+    Hard,
+    VeryHard,
+    Hardcore,
+    Extreme,
+    Insane,
+    Torment,
+    Lunatic,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
+impl RaidDifficulty {
+    /// Gets the difficulty at an index, where `0` is [`RaidDifficulty::Normal`].
+    pub fn from_index(index: u8) -> Self {
+        RaidDifficulty::iter()
+            .filter(|difficulty| !matches!(difficulty, RaidDifficulty::Unknown(_)))
+            .nth(index as usize)
+            .unwrap_or(RaidDifficulty::Unknown(index.to_string()))
+    }
+
+    /// Gets the index of the difficulty, where [`RaidDifficulty::Normal`] is `0`.
+    pub fn index(&self) -> Option<u8> {
+        RaidDifficulty::iter()
+            .position(|difficulty| &difficulty == self)
+            .filter(|_| !matches!(self, RaidDifficulty::Unknown(_)))
+            .map(|index| index as u8)
+    }
+}
+
+/// A difficulty in the data, which is either its index or its name.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+enum DifficultyValue {
+    Index(u8),
+    Name(String),
+}
+
+impl DifficultyValue {
+    fn difficulty(&self) -> RaidDifficulty {
+        match self {
+            Self::Index(index) => RaidDifficulty::from_index(*index),
+            Self::Name(name) => {
+                RaidDifficulty::from_str(name).unwrap_or(RaidDifficulty::Unknown(name.clone()))
+            }
+        }
+    }
+}
+
 impl Raid {
     pub fn armor(&self) -> crate::Armor {
         crate::Armor::from_str(&self.armor_type)
             .unwrap_or(crate::Armor::Unknown(self.armor_type.clone()))
     }
+
+    /**
+        Resolves the [`Raid::enemy_list`] of each difficulty into **[`Enemies`][`Enemy`]** out of the given ones,
+        reporting the ids that could not be resolved.
+
+        # Examples
+        ```
+        use blue_archive::Language;
+
+        #[tokio::main]
+        async fn main() -> anyhow::Result<()> {
+            let raid_data = blue_archive::fetch_raid_data(Language::English).await?;
+            let enemies = blue_archive::fetch_all_enemies(Language::English).await?;
+            let tiers = raid_data.raids[0].resolve_enemies(&enemies);
+            for (difficulty, tier) in tiers.tiers.iter().enumerate() {
+                for enemy in tier {
+                    println!("{difficulty}: {} ({} HP)", enemy.name, enemy.max_hp_100);
+                }
+            }
+            Ok(())
+        }
+        ```
+    */
+    pub fn resolve_enemies<'a>(&self, enemies: &'a [Enemy]) -> EnemyTiers<'a> {
+        EnemyTiers::resolve(&self.enemy_list, enemies)
+    }
+
+    /// Gets the **[`Skills`][`Skill`]** that the boss uses at a difficulty, where `0` is the easiest.
+    pub fn skills_at(&self, difficulty: usize) -> Vec<&Skill> {
+        skills_at(&self.skills, difficulty)
+    }
+}
+
+fn skills_at(skills: &[Skill], difficulty: usize) -> Vec<&Skill> {
+    skills
+        .iter()
+        .filter(|skill| skill.is_active_at(difficulty))
+        .collect()
+}
+
+/**
+    **A World Raid**, which is fought by every player of a region together, in one or more phases.
+
+    Unlike a **[`Raid`]**, its boss has a single pool of HP at each difficulty, which can change its armor and bullet type between phases.
+
+    # Examples
+    ```
+    use blue_archive::Language;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let raid_data = blue_archive::fetch_raid_data(Language::English).await?;
+        for world_raid in &raid_data.world_raid {
+            for (difficulty, name) in world_raid.difficulty_names().iter().enumerate() {
+                println!("{} ({name}): {:?} HP", world_raid.name, world_raid.hp(difficulty));
+            }
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct WorldRaid {
+    pub id: ID,
+    /// Whether the world raid has been **[`Released`]** in a specific region or not, see [`WorldRaid::regions`].
+    #[serde(alias = "IsReleased")]
+    pub released: Released,
+    pub path_name: String,
+    pub name: String,
+    pub faction: Option<Faction>,
+    #[serde(default)]
+    terrain: Vec<String>,
+    bullet_type: String,
+    armor_type: String,
+    /// The phases of the world raid, if it changes between them.
+    #[serde(default)]
+    phases: Vec<WorldRaidPhase>,
+    /// The ids of the **[`Enemies`][`Enemy`]** at each difficulty, see [`WorldRaid::resolve_enemies`].
+    #[serde(default)]
+    pub enemy_list: Vec<Vec<u32>>,
+    #[serde(alias = "RaidSkill", default)]
+    pub skills: Vec<Skill>,
+    pub icon: Option<String>,
+    #[serde(alias = "IconBG")]
+    pub icon_bg: Option<String>,
+    pub profile: Option<String>,
+    #[serde(default)]
+    difficulty_name: Vec<String>,
+    #[serde(default)]
+    difficulty_max: Vec<i8>,
+    #[serde(alias = "WorldBossHP", default)]
+    world_boss_hp: WorldBossHp,
+    #[serde(default)]
+    level: Vec<i32>,
+}
+
+impl WorldRaid {
+    /// Gets the **[`Terrains`][`Terrain`]** that the world raid is fought in.
+    pub fn terrains(&self) -> Vec<Terrain> {
+        self.terrain
+            .iter()
+            .map(|terrain| Terrain::from_str(terrain).unwrap_or(Terrain::Unknown(terrain.clone())))
+            .collect()
+    }
+
+    /// Gets the **[`Regions`][`Region`]** that the world raid has been released in.
+    pub fn regions(&self) -> Vec<Region> {
+        Region::iter()
+            .filter(|region| self.released.in_region(*region))
+            .collect()
+    }
+
+    /// The names of the difficulties of the world raid, from the easiest to the hardest.
+    pub fn difficulty_names(&self) -> &[String] {
+        &self.difficulty_name
+    }
+
+    /// The amount of difficulties of the world raid.
+    pub fn difficulty_count(&self) -> usize {
+        self.difficulty_name
+            .len()
+            .max(self.level.len())
+            .max(self.world_boss_hp.table().len())
+    }
+
+    /// The HP of the boss at a difficulty, where `0` is the easiest.
+    pub fn hp(&self, difficulty: usize) -> Option<u64> {
+        match &self.world_boss_hp {
+            WorldBossHp::Single(hp) => Some(*hp),
+            WorldBossHp::PerDifficulty(hp) => hp.get(difficulty).copied(),
+            WorldBossHp::None => None,
+        }
+    }
+
+    /// The HP of the boss at each difficulty, where a single value applies to every difficulty.
+    pub fn hp_table(&self) -> &[u64] {
+        self.world_boss_hp.table()
+    }
+
+    /// The level of the boss at a difficulty, where `0` is the easiest.
+    pub fn level(&self, difficulty: usize) -> Option<i32> {
+        self.level.get(difficulty).copied()
+    }
+
+    /// The level of the boss at each difficulty.
+    pub fn level_table(&self) -> &[i32] {
+        &self.level
+    }
+
+    /// The maximum of a difficulty, such as how many times it can be cleared, if there is one.
+    pub fn difficulty_max(&self, difficulty: usize) -> Option<i8> {
+        self.difficulty_max.get(difficulty).copied()
+    }
+
+    /// Resolves the [`WorldRaid::enemy_list`] of each difficulty into **[`Enemies`][`Enemy`]** out of the given ones,
+    /// reporting the ids that could not be resolved.
+    pub fn resolve_enemies<'a>(&self, enemies: &'a [Enemy]) -> EnemyTiers<'a> {
+        EnemyTiers::resolve(&self.enemy_list, enemies)
+    }
+
+    /// Gets the **[`Skills`][`Skill`]** that the boss uses at a difficulty, where `0` is the easiest.
+    pub fn skills_at(&self, difficulty: usize) -> Vec<&Skill> {
+        skills_at(&self.skills, difficulty)
+    }
+
+    /// Whether the world raid changes its armor or bullet type between phases.
+    pub fn has_phases(&self) -> bool {
+        !self.phases.is_empty()
+    }
+
+    /**
+        Gets the **[`WorldRaidPhases`][`WorldRaidPhase`]** of the world raid, in order.
+
+        A world raid that does not change between phases has a single phase with its own armor and bullet type.
+    */
+    pub fn phases(&self) -> Vec<WorldRaidPhase> {
+        match self.phases.is_empty() {
+            true => vec![WorldRaidPhase {
+                bullet_type: self.bullet_type.clone(),
+                armor_type: self.armor_type.clone(),
+                enemy_list: vec![],
+            }],
+            false => self.phases.clone(),
+        }
+    }
+}
+
+/// The HP of the boss of a **[`WorldRaid`]**, which is either the same at every difficulty or given per difficulty.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+enum WorldBossHp {
+    Single(u64),
+    PerDifficulty(Vec<u64>),
+    #[default]
+    None,
+}
+
+impl WorldBossHp {
+    fn table(&self) -> &[u64] {
+        match self {
+            Self::Single(hp) => std::slice::from_ref(hp),
+            Self::PerDifficulty(hp) => hp,
+            Self::None => &[],
+        }
+    }
+}
+
+/// **A phase of a [`WorldRaid`]**, which has its own armor and bullet type.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct WorldRaidPhase {
+    bullet_type: String,
+    armor_type: String,
+    /// The ids of the **[`Enemies`][`Enemy`]** of the phase at each difficulty, if it has its own.
+    #[serde(default)]
+    pub enemy_list: Vec<Vec<u32>>,
+}
+
+impl WorldRaidPhase {
+    /// Gets the **[`BulletType`]** of the boss during the phase.
+    pub fn bullet_type(&self) -> BulletType {
+        BulletType::from_str(&self.bullet_type)
+            .unwrap_or(BulletType::Unknown(self.bullet_type.clone()))
+    }
+
+    /// Gets the **[`Armor`]** of the boss during the phase.
+    pub fn armor(&self) -> Armor {
+        Armor::from_str(&self.armor_type).unwrap_or(Armor::Unknown(self.armor_type.clone()))
+    }
+}
+
+/**
+    **A Joint Firing Drill**, in which a team that meets its restrictions is scored on clearing a dungeon.
+
+    # Examples
+    ```
+    use blue_archive::Language;
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let raid_data = blue_archive::fetch_raid_data(Language::English).await?;
+        let students = blue_archive::fetch_all_students(Language::English).await?;
+        for drill in &raid_data.time_attack {
+            println!(
+                "{} ({}): {} eligible students",
+                drill.dungeon_type(),
+                drill.terrain(),
+                drill.eligible_students(&students).len()
+            );
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeAttack {
+    pub id: ID,
+    /// Whether the drill has been **[`Released`]** in a specific region or not.
+    #[serde(alias = "IsReleased")]
+    pub released: Released,
+    dungeon_type: String,
+    pub icon: Option<String>,
+    terrain: String,
+    bullet_type: String,
+    armor_type: String,
+    /// The restrictions that each **[`Student`]** of a team must meet, see [`TimeAttack::is_eligible`].
+    #[serde(default)]
+    pub restrictions: Vec<Restriction>,
+    /// The level of the enemies at each difficulty.
+    #[serde(default)]
+    pub enemy_level: Vec<u16>,
+    /// The formations of enemies that are fought.
+    #[serde(default)]
+    pub formations: Vec<TimeAttackFormation>,
+    /// The ids of the **[`TimeAttackRules`][`TimeAttackRule`]** at each difficulty, see [`RaidData::rules_of`].
+    #[serde(default)]
+    rules: Vec<Vec<RuleId>>,
+}
+
+impl TimeAttack {
+    /// Gets the **[`DungeonType`]** of the drill.
+    pub fn dungeon_type(&self) -> DungeonType {
+        DungeonType::from_str(&self.dungeon_type)
+            .unwrap_or(DungeonType::Unknown(self.dungeon_type.clone()))
+    }
+
+    /// Gets the **[`Terrain`]** of the drill.
+    pub fn terrain(&self) -> Terrain {
+        Terrain::from_str(&self.terrain).unwrap_or(Terrain::Unknown(self.terrain.clone()))
+    }
+
+    /// Gets the **[`BulletType`]** of the enemies of the drill.
+    pub fn bullet_type(&self) -> BulletType {
+        BulletType::from_str(&self.bullet_type)
+            .unwrap_or(BulletType::Unknown(self.bullet_type.clone()))
+    }
+
+    /// Gets the **[`Armor`]** of the enemies of the drill.
+    pub fn armor(&self) -> Armor {
+        Armor::from_str(&self.armor_type).unwrap_or(Armor::Unknown(self.armor_type.clone()))
+    }
+
+    /// The ids of the **[`TimeAttackRules`][`TimeAttackRule`]** at a difficulty, where `0` is the easiest.
+    pub fn rule_ids(&self, difficulty: usize) -> Vec<u32> {
+        self.rules
+            .get(difficulty)
+            .map(|rules| rules.iter().map(RuleId::id).collect())
+            .unwrap_or_default()
+    }
+
+    /**
+        Whether a **[`Student`]** meets every one of the [`TimeAttack::restrictions`] of the drill.
+
+        A restriction on a property or with an operand that is not known, such as a new kind of restriction, is not met.
+    */
+    pub fn is_eligible(&self, student: &Student) -> bool {
+        self.restrictions
+            .iter()
+            .all(|restriction| restriction_is_met(restriction, student))
+    }
+
+    /// Gets the **[`Students`][`Student`]** out of the given ones that are eligible for the drill.
+    pub fn eligible_students<'a>(&self, students: &'a [Student]) -> Vec<&'a Student> {
+        students
+            .iter()
+            .filter(|student| self.is_eligible(student))
+            .collect()
+    }
+}
+
+/// Whether a **[`Student`]** meets a **[`Restriction`]**, by the property that it restricts.
+fn restriction_is_met(restriction: &Restriction, student: &Student) -> bool {
+    let value = match &restriction.value {
+        RestrictValue::String(value) => value.clone(),
+        RestrictValue::I32(value) => value.to_string(),
+    };
+    let matches = match restriction.property.as_str() {
+        "ArmorType" => Armor::from_str(&value).is_ok_and(|armor| student.armor() == armor),
+        "BulletType" => {
+            BulletType::from_str(&value).is_ok_and(|bullet| student.bullet_type() == bullet)
+        }
+        "SquadType" => Squad::from_str(&value).is_ok_and(|squad| student.squad() == squad),
+        "TacticRole" => {
+            TacticalRole::from_str(&value).is_ok_and(|role| student.tactical_role() == role)
+        }
+        "Position" => {
+            Position::from_str(&value).is_ok_and(|position| student.position() == position)
+        }
+        "WeaponType" => {
+            WeaponType::from_str(&value).is_ok_and(|weapon| student.weapon_type() == weapon)
+        }
+        "School" => School::from_str(&value).is_ok_and(|school| student.school() == school),
+        _ => return false,
+    };
+    match restriction.operand.as_str() {
+        "Equal" => matches,
+        "NotEqual" => !matches,
+        _ => false,
+    }
+}
+
+/**
+    The type of dungeon of a **[`TimeAttack`]**.
+
+    In the case that a dungeon type in the data is not present on the wrapper,
+    a [`DungeonType::Unknown(String)`] is returned to represent the unknown dungeon type with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum DungeonType {
+    /// Targets are shot down as fast as possible.
+    Shooting,
+    /// Waves of enemies are held off.
+    Defense,
+    /// Objects are destroyed as fast as possible.
+    Destruction,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
 }
 
-// #[derive(Debug, Serialize, Deserialize, PartialEq,)]
-// #[serde(rename_all = "PascalCase")]
-// pub struct RaidSeason;
+/// A formation of enemies in a **[`TimeAttack`]**.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeAttackFormation {
+    pub id: u32,
+    /// The level of the enemies at each difficulty.
+    #[serde(default)]
+    pub level: Vec<u16>,
+    /// The ids of the **[`Enemies`][`Enemy`]** of the formation.
+    #[serde(default)]
+    pub enemy_list: Vec<u32>,
+}
 
-// #[derive(Debug, Serialize, Deserialize, PartialEq,)]
-// #[serde(rename_all = "PascalCase")]
-// pub struct TimeAttack;
+/// The id of a rule, which is either the id itself or an object with it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+enum RuleId {
+    Id(u32),
+    Object {
+        #[serde(alias = "Id")]
+        id: u32,
+    },
+}
 
-// #[derive(Debug, Serialize, Deserialize, PartialEq,)]
-// #[serde(rename_all = "PascalCase")]
-// pub struct TimeAttackRule;
+impl RuleId {
+    fn id(&self) -> u32 {
+        match self {
+            Self::Id(id) | Self::Object { id } => *id,
+        }
+    }
+}
 
-/// **A [`Raid`] specific Skill**.
+/// **A rule of a [`TimeAttack`]**, which changes how the drill is played at some difficulties.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
-#[serde(tag = "SkillType", rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeAttackRule {
+    pub id: ID,
+    pub name: String,
+    pub icon: Option<String>,
+    #[serde(
+        alias = "Desc",
+        default,
+        deserialize_with = "serialization::deserialize_html_encoded_string"
+    )]
+    pub description: String,
+    /// The values of the parameters of the description, such as `<?1>`, at each level of the rule.
+    #[serde(default)]
+    pub parameters: Vec<Vec<String>>,
+}
+
+impl TimeAttackRule {
+    /**
+        The description of the rule as plain text, with its parameters filled in with their values at a level, where `0` is the first.
+
+        Returns [`None`] if a placeholder of the description, such as `<?1>`, has no value at `level`,
+        see **[`DescriptionRenderer`]**.
+    */
+    pub fn description_at(&self, level: usize) -> Option<String> {
+        DescriptionRenderer::default().render(&self.description, &self.parameters, level)
+    }
+}
+
+/**
+    **A [`Raid`] specific Skill**.
+
+    The accessors work across every kind of skill, returning [`None`] for what a kind does not have,
+    such as the name of a [`Skill::RaidAutoAttack`].
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(
+    tag = "SkillType",
+    rename_all = "PascalCase",
+    rename_all_fields = "PascalCase"
+)]
 pub enum Skill {
     #[serde(alias = "normal")]
     Normal {
@@ -91,6 +743,8 @@ pub enum Skill {
             deserialize_with = "serialization::deserialize_html_encoded_string"
         )]
         description: String,
+        #[serde(skip, default = "serialization::default_image_uri")]
+        image_uri: String,
     },
     #[serde(alias = "raidautoattack")]
     RaidAutoAttack {
@@ -101,11 +755,123 @@ pub enum Skill {
         atg_cost: u8,
         icon: Option<String>,
         effects: Option<Vec<Effect>>,
+        #[serde(skip, default = "serialization::default_image_uri")]
+        image_uri: String,
     },
     EX(SpecialRaidSkill),
     Passive(SpecialRaidSkill),
 }
 
+impl Skill {
+    /// The id of the skill.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Normal { id, .. } | Self::RaidAutoAttack { id, .. } => id,
+            Self::EX(skill) | Self::Passive(skill) => skill.id(),
+        }
+    }
+
+    /// The name of the skill, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Normal { name, .. } => name.as_deref(),
+            Self::RaidAutoAttack { .. } => None,
+            Self::EX(skill) | Self::Passive(skill) => skill.name(),
+        }
+    }
+
+    /**
+        Renders the description of the skill as plain text at a difficulty, where `0` is the easiest, see **[`DescriptionRenderer`]**.
+
+        Returns [`None`] if the skill has no description, or if a placeholder of the description, such as `<?1>`,
+        has no value at `difficulty`.
+    */
+    pub fn render_description(&self, difficulty: usize) -> Option<String> {
+        self.render_description_with(difficulty, &DescriptionRenderer::default())
+    }
+
+    /// Renders the description of the skill at a difficulty, where `0` is the easiest, with a **[`DescriptionRenderer`]**.
+    pub fn render_description_with(
+        &self,
+        difficulty: usize,
+        renderer: &DescriptionRenderer,
+    ) -> Option<String> {
+        match self {
+            Self::Normal { description, .. } => renderer.render(description, &[], difficulty),
+            Self::RaidAutoAttack { .. } => None,
+            Self::EX(skill) | Self::Passive(skill) => {
+                skill.render_description_with(difficulty, renderer)
+            }
+        }
+    }
+
+    /// The lowest difficulty that the skill is used at, where `0` is the easiest, or [`None`] if it is used at every difficulty.
+    pub fn min_difficulty(&self) -> Option<u8> {
+        match self {
+            Self::Normal { min_difficulty, .. } | Self::RaidAutoAttack { min_difficulty, .. } => {
+                *min_difficulty
+            }
+            Self::EX(skill) | Self::Passive(skill) => skill.min_difficulty(),
+        }
+    }
+
+    /// Whether the skill is used at a difficulty, where `0` is the easiest.
+    pub fn is_active_at(&self, difficulty: usize) -> bool {
+        match self.min_difficulty() {
+            Some(min_difficulty) => difficulty >= min_difficulty as usize,
+            None => true,
+        }
+    }
+
+    /// The cost of the skill in the gauge (ATG) of the boss.
+    pub fn atg_cost(&self) -> u8 {
+        match self {
+            Self::Normal { atg_cost, .. } | Self::RaidAutoAttack { atg_cost, .. } => *atg_cost,
+            Self::EX(skill) | Self::Passive(skill) => skill.atg_cost(),
+        }
+    }
+
+    /// Returns the url of the skill icon, if it has one.
+    pub fn icon_url(&self) -> Option<String> {
+        match self {
+            Self::Normal {
+                icon, image_uri, ..
+            }
+            | Self::RaidAutoAttack {
+                icon, image_uri, ..
+            } => icon
+                .as_ref()
+                .map(|icon| raid_skill_icon_url(image_uri, icon)),
+            Self::EX(skill) | Self::Passive(skill) => Some(skill.icon_url()),
+        }
+    }
+
+    /// The **[`Effects`][`Effect`]** of the skill.
+    pub fn effects(&self) -> &[Effect] {
+        match self {
+            Self::Normal { .. } => &[],
+            Self::RaidAutoAttack { effects, .. } => effects.as_deref().unwrap_or_default(),
+            Self::EX(skill) | Self::Passive(skill) => skill.effects(),
+        }
+    }
+
+    /// Builds the icon url of this skill relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, uri: &str) {
+        match self {
+            Self::Normal { image_uri, .. } | Self::RaidAutoAttack { image_uri, .. } => {
+                *image_uri = uri.to_string()
+            }
+            Self::EX(skill) | Self::Passive(skill) => skill.image_uri = uri.to_string(),
+        }
+    }
+}
+
+/// The url of the icon of a raid skill relative to the given `image_uri`.
+fn raid_skill_icon_url(image_uri: &str, icon: &str) -> String {
+    format!("{image_uri}/raid/skill/{icon}.webp")
+}
+
+/// **An EX or passive skill of a [`Raid`]**, which has parameters that change with the difficulty.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct SpecialRaidSkill {
@@ -123,6 +889,63 @@ pub struct SpecialRaidSkill {
     )]
     pub description: String,
     parameters: Option<Vec<Vec<String>>>,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
+}
+
+impl SpecialRaidSkill {
+    /// The id of the skill.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The name of the skill, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The values of the parameters of the description, such as `<?1>`, at each difficulty.
+    pub fn parameters(&self) -> &[Vec<String>] {
+        self.parameters.as_deref().unwrap_or_default()
+    }
+
+    /**
+        Renders the description of the skill as plain text at a difficulty, where `0` is the easiest, see **[`DescriptionRenderer`]**.
+
+        Returns [`None`] if a placeholder of the description, such as `<?1>`, has no value at `difficulty`.
+    */
+    pub fn render_description(&self, difficulty: usize) -> Option<String> {
+        self.render_description_with(difficulty, &DescriptionRenderer::default())
+    }
+
+    /// Renders the description of the skill at a difficulty, where `0` is the easiest, with a **[`DescriptionRenderer`]**.
+    pub fn render_description_with(
+        &self,
+        difficulty: usize,
+        renderer: &DescriptionRenderer,
+    ) -> Option<String> {
+        renderer.render(&self.description, self.parameters(), difficulty)
+    }
+
+    /// The lowest difficulty that the skill is used at, where `0` is the easiest, or [`None`] if it is used at every difficulty.
+    pub fn min_difficulty(&self) -> Option<u8> {
+        self.min_difficulty
+    }
+
+    /// The cost of the skill in the gauge (ATG) of the boss.
+    pub fn atg_cost(&self) -> u8 {
+        self.atg_cost
+    }
+
+    /// Returns the url of the skill icon.
+    pub fn icon_url(&self) -> String {
+        raid_skill_icon_url(&self.image_uri, &self.icon)
+    }
+
+    /// The **[`Effects`][`Effect`]** of the skill.
+    pub fn effects(&self) -> &[Effect] {
+        self.effects.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Display, Deserialize, Serialize, PartialEq, Clone, EnumString)]
@@ -0,0 +1,446 @@
+//! Contains the [`StageData`] structure and its respective structures.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+use crate::{serialization, Terrain};
+
+use super::{
+    currency::Currency,
+    enemy::{Enemy, EnemyTiers},
+    equipment::{Equipment, EquipmentCategory},
+    item::ItemIndex,
+    Item,
+};
+
+/**
+    Contains the **[`Stages`][`Stage`]** of the game, such as the main campaign, bounties, commissions, scrimmages and events.
+
+    # Examples
+    ```
+    use blue_archive::{types::equipment::EquipmentCategory, Language};
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let stages = blue_archive::fetch_stage_data(Language::English).await?;
+        let equipment = blue_archive::fetch_all_equipment(Language::English).await?;
+        for drop in stages.stages_dropping(&EquipmentCategory::Hat, &equipment) {
+            println!(
+                "{} ({}) drops {} at {}%",
+                drop.stage.name,
+                drop.kind,
+                drop.equipment.name,
+                drop.reward.chance * 100.0
+            );
+        }
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StageData {
+    /// The stages of the main campaign, both normal and hard, see [`Stage::difficulty`].
+    #[serde(default)]
+    pub campaign: Vec<Stage>,
+    /// The stages of the bounties and commissions.
+    #[serde(default)]
+    pub week_dungeon: Vec<Stage>,
+    /// The stages of the scrimmages.
+    #[serde(default)]
+    pub school_dungeon: Vec<Stage>,
+    /// The stages of the events, see [`Stage::event_id`].
+    #[serde(default)]
+    pub event: Vec<Stage>,
+}
+
+impl StageData {
+    /// Iterates over every **[`Stage`]** alongside its **[`StageKind`]**.
+    pub fn iter(&self) -> impl Iterator<Item = (StageKind, &Stage)> {
+        let campaign = self.campaign.iter().map(|stage| {
+            let kind = match stage.difficulty {
+                0 => StageKind::Campaign,
+                _ => StageKind::Hard,
+            };
+            (kind, stage)
+        });
+        let week_dungeon = self.week_dungeon.iter().map(|stage| {
+            let kind = match stage.dungeon_type.as_deref() {
+                Some(dungeon_type) if dungeon_type.starts_with("Chaser") => StageKind::Bounty,
+                _ => StageKind::Commission,
+            };
+            (kind, stage)
+        });
+        let school_dungeon = self
+            .school_dungeon
+            .iter()
+            .map(|stage| (StageKind::Scrimmage, stage));
+        let event = self.event.iter().map(|stage| (StageKind::Event, stage));
+        campaign
+            .chain(week_dungeon)
+            .chain(school_dungeon)
+            .chain(event)
+    }
+
+    /// Gets a **[`Stage`]** by its id.
+    pub fn get(&self, id: u32) -> Option<&Stage> {
+        self.iter()
+            .map(|(_, stage)| stage)
+            .find(|stage| stage.id == id)
+    }
+
+    /// Gets the **[`Stages`][`Stage`]** of a **[`StageKind`]**.
+    pub fn stages(&self, kind: StageKind) -> Vec<&Stage> {
+        self.iter()
+            .filter(|(stage_kind, _)| *stage_kind == kind)
+            .map(|(_, stage)| stage)
+            .collect()
+    }
+
+    /// Gets the stages of an event by the id of the event.
+    pub fn event_stages(&self, event_id: u32) -> Vec<&Stage> {
+        self.event
+            .iter()
+            .filter(|stage| stage.event_id == Some(event_id))
+            .collect()
+    }
+
+    /**
+        Gets every **[`StageDrop`]** of an **[`Equipment`]** of the given **[`EquipmentCategory`]**, such as the stages that drop hat blueprints.
+
+        The drops are sorted by their chance, from the highest to the lowest.
+    */
+    pub fn stages_dropping<'a>(
+        &'a self,
+        category: &EquipmentCategory,
+        equipment: &'a [Equipment],
+    ) -> Vec<StageDrop<'a>> {
+        let mut drops = self
+            .iter()
+            .flat_map(|(kind, stage)| {
+                stage
+                    .equipment_drops(equipment)
+                    .into_iter()
+                    .map(move |(reward, equipment)| StageDrop {
+                        kind,
+                        stage,
+                        reward,
+                        equipment,
+                    })
+            })
+            .filter(|drop| &drop.equipment.category == category)
+            .collect::<Vec<_>>();
+        drops.sort_by(|a, b| b.reward.chance.total_cmp(&a.reward.chance));
+        drops
+    }
+}
+
+/**
+    The kind of a **[`Stage`]**, which is based on where it is in the **[`StageData`]**.
+*/
+#[derive(Debug, Display, EnumIter, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum StageKind {
+    /// A normal stage of the main campaign.
+    Campaign,
+    /// A hard stage of the main campaign.
+    Hard,
+    /// A bounty, which drops artifacts.
+    Bounty,
+    /// A commission, which drops credits or activity reports.
+    Commission,
+    /// A scrimmage, which drops tech notes and blu-rays.
+    Scrimmage,
+    /// A stage of an event.
+    Event,
+}
+
+/**
+    **A stage of the game**, such as `1-1` of the main campaign.
+
+    The enemies of a stage are in its [`Stage::formations`], one after another, which can be resolved with [`Stage::resolve_enemies`].
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Stage {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    /// The area of the main campaign that the stage is in.
+    pub area: Option<u32>,
+    /// The number of the stage in its area or dungeon.
+    pub stage: Option<u32>,
+    /// The difficulty of the stage, where `0` is normal and `1` is hard in the main campaign.
+    #[serde(default)]
+    pub difficulty: u32,
+    #[serde(rename = "Type", default)]
+    dungeon_type: Option<String>,
+    /// The id of the event that the stage belongs to.
+    pub event_id: Option<u32>,
+    /// The recommended level of the stage.
+    pub level: Option<u32>,
+    /// What it costs to enter the stage, see **[`EntryCost`]**.
+    #[serde(default)]
+    pub entry_cost: Vec<EntryCost>,
+    #[serde(default)]
+    terrain: Option<String>,
+    #[serde(default)]
+    pub formations: Vec<StageFormation>,
+    /// The rewards of the stage, see **[`DropTable`]**.
+    #[serde(default)]
+    pub rewards: DropTable,
+}
+
+impl Stage {
+    /// Gets the **[`Terrain`]** of the stage, if it has one.
+    pub fn terrain(&self) -> Option<Terrain> {
+        self.terrain
+            .as_ref()
+            .map(|terrain| Terrain::from_str(terrain).unwrap_or(Terrain::Unknown(terrain.clone())))
+    }
+
+    /**
+        Resolves the enemies of each formation out of the given enemies, such as the ones from [`crate::fetch_all_enemies`].
+
+        Each tier of the **[`EnemyTiers`]** is a formation, in the order of [`Stage::formations`].
+    */
+    pub fn resolve_enemies<'a>(&self, enemies: &'a [Enemy]) -> EnemyTiers<'a> {
+        let enemy_list = self
+            .formations
+            .iter()
+            .map(|formation| formation.enemy_list.clone())
+            .collect::<Vec<_>>();
+        EnemyTiers::resolve(&enemy_list, enemies)
+    }
+
+    /// Gets the **[`Equipment`]** that the stage drops by default, alongside the **[`StageReward`]** of each.
+    pub fn equipment_drops<'a>(
+        &'a self,
+        equipment: &'a [Equipment],
+    ) -> Vec<(&'a StageReward, &'a Equipment)> {
+        self.rewards
+            .default
+            .iter()
+            .filter_map(|reward| reward.equipment(equipment).map(|piece| (reward, piece)))
+            .collect()
+    }
+}
+
+/// What it costs to enter a **[`Stage`]**, which is an amount of a **[`Currency`]** such as AP.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(from = "[u32; 2]", into = "[u32; 2]")]
+pub struct EntryCost {
+    /// The id of the **[`Currency`]**.
+    pub currency_id: u32,
+    pub amount: u32,
+}
+
+impl EntryCost {
+    /// Gets the **[`Currency`]** of the cost out of the given currencies.
+    pub fn currency<'a>(&self, currencies: &'a [Currency]) -> Option<&'a Currency> {
+        currencies
+            .iter()
+            .find(|currency| currency.id.to_u32() == self.currency_id)
+    }
+}
+
+impl From<[u32; 2]> for EntryCost {
+    fn from([currency_id, amount]: [u32; 2]) -> Self {
+        Self {
+            currency_id,
+            amount,
+        }
+    }
+}
+
+impl From<EntryCost> for [u32; 2] {
+    fn from(cost: EntryCost) -> Self {
+        [cost.currency_id, cost.amount]
+    }
+}
+
+/// A formation of enemies in a **[`Stage`]**.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StageFormation {
+    #[serde(default)]
+    pub id: u32,
+    /// The ids of the **[`Enemies`][`Enemy`]** of the formation.
+    #[serde(default)]
+    pub enemy_list: Vec<u32>,
+}
+
+/// The rewards of a **[`Stage`]**.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DropTable {
+    /// The rewards of every clear.
+    #[serde(default)]
+    pub default: Vec<StageReward>,
+    /// The rewards of the first clear.
+    #[serde(default)]
+    pub first_clear: Vec<StageReward>,
+    /// The rewards of the first clear with every star.
+    #[serde(default)]
+    pub three_star: Vec<StageReward>,
+}
+
+/**
+    **A reward of a [`Stage`]**, with the chance of it dropping.
+
+    The data either has the rewards as objects with a type, or as `[id, chance, amount]` arrays,
+    in which case the [`StageReward::reward_type`] is not known. An array needs at least the id and the chance,
+    its id and amount have to be whole numbers, and its chance has to be between `0.0` and `1.0`.
+
+    As the id of a reward without a type could be either an equipment or an item, such a reward is looked up in both
+    by [`StageReward::equipment`] and [`StageReward::item`], and may be found in both if their ids overlap.
+*/
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase", try_from = "RawStageReward")]
+pub struct StageReward {
+    #[serde(rename = "Type")]
+    reward_type: Option<String>,
+    pub id: u32,
+    /// The chance of the reward dropping, where `1.0` is guaranteed.
+    pub chance: f64,
+    pub amount: u32,
+}
+
+impl StageReward {
+    /// Gets the **[`RewardType`]** of the reward, if it is known.
+    pub fn reward_type(&self) -> Option<RewardType> {
+        self.reward_type.as_ref().map(|reward_type| {
+            RewardType::from_str(reward_type).unwrap_or(RewardType::Unknown(reward_type.clone()))
+        })
+    }
+
+    /// The amount that drops on average, which is the amount times the chance.
+    pub fn expected_amount(&self) -> f64 {
+        self.amount as f64 * self.chance
+    }
+
+    /// Gets the **[`Equipment`]** of the reward out of the given equipment, if the reward is one.
+    ///
+    /// A reward without a type is looked up by its id, see **[`StageReward`]**.
+    pub fn equipment<'a>(&self, equipment: &'a [Equipment]) -> Option<&'a Equipment> {
+        match self.reward_type() {
+            None | Some(RewardType::Equipment) => {
+                equipment.iter().find(|piece| piece.id.to_u32() == self.id)
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Gets the **[`Item`]** of the reward out of an **[`ItemIndex`]**, if the reward is one.
+    ///
+    /// A reward without a type is looked up by its id, see **[`StageReward`]**.
+    pub fn item<'a>(&self, items: &'a ItemIndex) -> Option<&'a Item> {
+        match self.reward_type() {
+            None | Some(RewardType::Item) => items.get(self.id),
+            Some(_) => None,
+        }
+    }
+}
+
+/// The shapes that a **[`StageReward`]** is in the data.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawStageReward {
+    #[serde(rename_all = "PascalCase")]
+    Typed {
+        #[serde(rename = "Type", default)]
+        reward_type: Option<String>,
+        id: u32,
+        #[serde(alias = "Probability", default = "serialization::default_chance")]
+        chance: f64,
+        #[serde(default = "serialization::default_amount")]
+        amount: u32,
+    },
+    Listed(Vec<f64>),
+}
+
+/// Checks the chance of a `[id, chance, amount]` array, failing if it is not between `0.0` and `1.0`.
+fn chance_of(value: f64) -> Result<f64, String> {
+    match (0.0..=1.0).contains(&value) {
+        true => Ok(value),
+        false => Err(format!(
+            "the chance of a reward must be between 0 and 1, got {value}"
+        )),
+    }
+}
+
+/// Turns a value of a `[id, chance, amount]` array into a whole number, failing if it is negative or has a fraction.
+fn whole_number(value: f64, name: &str) -> Result<u32, String> {
+    match value.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&value) {
+        true => Ok(value as u32),
+        false => Err(format!(
+            "the {name} of a reward must be a whole number, got {value}"
+        )),
+    }
+}
+
+impl TryFrom<RawStageReward> for StageReward {
+    type Error = String;
+
+    fn try_from(raw: RawStageReward) -> Result<Self, Self::Error> {
+        match raw {
+            RawStageReward::Typed {
+                reward_type,
+                id,
+                chance,
+                amount,
+            } => Ok(Self {
+                reward_type,
+                id,
+                chance,
+                amount,
+            }),
+            RawStageReward::Listed(values) => match values[..] {
+                [id, chance] | [id, chance, _] => {
+                    let amount = match values.get(2) {
+                        Some(amount) => whole_number(*amount, "amount")?,
+                        None => serialization::default_amount(),
+                    };
+                    Ok(Self {
+                        reward_type: None,
+                        id: whole_number(id, "id")?,
+                        chance: chance_of(chance)?,
+                        amount,
+                    })
+                }
+                _ => Err(format!(
+                    "a reward must be a `[id, chance]` or `[id, chance, amount]` array, got {} values",
+                    values.len()
+                )),
+            },
+        }
+    }
+}
+
+/**
+    The type of a **[`StageReward`]**.
+
+    In the case that a type in the data is not present on the wrapper,
+    a [`RewardType::Unknown(String)`] is returned to represent the unknown type with its name in the `enum`.
+*/
+#[derive(Debug, Display, EnumString, EnumIter, PartialEq, Eq, Clone)]
+pub enum RewardType {
+    Equipment,
+    Item,
+    Currency,
+    Furniture,
+    /// A student, which is called a character in the data.
+    Character,
+    /// An **`unknown`** type that contains the inner value.
+    Unknown(String),
+}
+
+/// A **[`Stage`]** that drops an **[`Equipment`]**, see [`StageData::stages_dropping`].
+#[derive(Debug, Clone, Copy)]
+pub struct StageDrop<'a> {
+    pub kind: StageKind,
+    pub stage: &'a Stage,
+    pub reward: &'a StageReward,
+    pub equipment: &'a Equipment,
+}
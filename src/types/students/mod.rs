@@ -1,13 +1,17 @@
 //! Contains types for [`Students`][`Student`].
 
+pub mod stats;
 pub mod student;
 use std::fmt::Display;
 
 use serde::ser::SerializeStruct;
 
 use serde::{Deserialize, Serialize};
+pub use stats::{StatBlock, StatBonus, StatCalculator, StudentStats};
 pub use student::Student;
 
+use crate::Region;
+
 /// The age of a **[`Student`]**, which can be **[`None`]** or a **[`u8`]**, depending on if the age can be parsed or not.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Age(pub Option<u8>);
@@ -99,6 +103,17 @@ impl<'de> Deserialize<'de> for Released {
     }
 }
 
+impl Released {
+    /// Whether it has been released in the given **[`Region`]**.
+    pub fn in_region(&self, region: Region) -> bool {
+        match region {
+            Region::Japan => self.japan,
+            Region::Global => self.global,
+            Region::China => self.china,
+        }
+    }
+}
+
 impl Display for Released {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
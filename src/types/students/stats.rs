@@ -0,0 +1,428 @@
+//! Contains the [`StatCalculator`], which computes the stats of a [`Student`] at a level, star grade and weapon level,
+//! with their equipment, bond and gear.
+
+use std::{
+    ops::{Add, AddAssign},
+    str::FromStr,
+};
+
+use crate::types::equipment::{Equipment, EquipmentCategory};
+
+use super::{
+    student::{LevelUpType, Weapon},
+    Student,
+};
+
+// The bonuses of each star grade are the `Transcendence` rates of the stat calculation of ``SchaleDB``,
+// which raise a stat by the sum of the rates up to the star grade: `stat * (10000 + sum) / 10000`,
+// rounded to the closest whole number like every other rate, see `raise`.
+
+/// The bonus of each star grade to the attack power, in ten-thousandths, where the first entry is for the first star.
+const STAR_GRADE_ATTACK_POWER: [u32; 5] = [0, 1000, 1200, 1400, 1700];
+/// The bonus of each star grade to the max HP, in ten-thousandths.
+const STAR_GRADE_MAX_HP: [u32; 5] = [0, 500, 700, 900, 1400];
+/// The bonus of each star grade to the healing, in ten-thousandths.
+const STAR_GRADE_HEAL_POWER: [u32; 5] = [0, 750, 1000, 1200, 1500];
+
+/// The attack power, max HP, defense and healing of a **[`Student`]**.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct StudentStats {
+    pub attack_power: u32,
+    pub max_hp: u32,
+    pub defense_power: u32,
+    pub heal_power: u32,
+}
+
+impl StudentStats {
+    /// Adds a value to a stat by its name in the data, such as `AttackPower` or `MaxHP`, ignoring the stats that are not represented.
+    fn add_stat(&mut self, stat: &str, value: u32) {
+        let stat = match stat {
+            "AttackPower" => &mut self.attack_power,
+            "MaxHP" => &mut self.max_hp,
+            "DefensePower" => &mut self.defense_power,
+            "HealPower" => &mut self.heal_power,
+            _ => return,
+        };
+        *stat = stat.saturating_add(value);
+    }
+}
+
+impl Add for StudentStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            attack_power: self.attack_power.saturating_add(other.attack_power),
+            max_hp: self.max_hp.saturating_add(other.max_hp),
+            defense_power: self.defense_power.saturating_add(other.defense_power),
+            heal_power: self.heal_power.saturating_add(other.heal_power),
+        }
+    }
+}
+
+impl AddAssign for StudentStats {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/**
+    The stats that a source adds to a **[`Student`]**.
+
+    The stats of the data are either added as they are, such as `AttackPower_Base`, or raise the stat by a rate,
+    such as `AttackPower_Coefficient`, which is in ten-thousandths where `1000` is 10%.
+*/
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct StatBonus {
+    /// The stats that are added as they are.
+    pub flat: StudentStats,
+    /// The rates that the stats are raised by, in ten-thousandths.
+    pub coefficient: StudentStats,
+}
+
+impl StatBonus {
+    /// Adds a value to a stat by its name in the data, such as `AttackPower_Base` or `MaxHP_Coefficient`.
+    fn add_stat(&mut self, stat: &str, value: u32) {
+        match stat.split_once('_') {
+            Some((stat, "Coefficient")) => self.coefficient.add_stat(stat, value),
+            Some((stat, _)) => self.flat.add_stat(stat, value),
+            None => self.flat.add_stat(stat, value),
+        }
+    }
+}
+
+/**
+    **The stats of a [`Student`] by their source**, alongside the final stats, see [`StatCalculator::breakdown`].
+
+    The final stats are the sum of the base, weapon, bond and flat equipment and gear stats,
+    which are then raised by the rates of the equipment and gear.
+*/
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct StatBlock {
+    /// The stats at the level and star grade of the student.
+    pub base: StudentStats,
+    /// The stats of the **[`Weapon`]**.
+    pub weapon: StudentStats,
+    /// The stats of the **[`Equipment`]** in the slots of the student.
+    pub equipment: StatBonus,
+    /// The stats of the bond of the student, and of the alternate versions of the student.
+    pub bond: StudentStats,
+    /// The stats of the unique **[`Gear`][`super::student::Gear`]**.
+    pub gear: StatBonus,
+    /// The final stats.
+    pub total: StudentStats,
+}
+
+/**
+    **Computes the stats of a [`Student`]** at a level, star grade and weapon level, the same way as ``SchaleDB``.
+
+    The stats at a level are between the ones at level 1 and level 100 of the data, such as [`Student::attack_power_1`] and
+    [`Student::attack_power_100`], and levels past 100 keep on growing the same way.
+    The star grade then raises the attack power, max HP and healing, and the **[`Weapon`]** adds its own stats on top,
+    growing along the curve of its [`LevelUpType`].
+
+    The equipment, bond and gear of the student can be layered on top, which [`StatCalculator::breakdown`] shows by source.
+    Only the attack power, max HP, defense and healing are computed, any other stat of the data is left out.
+
+    # Examples
+    ```
+    use blue_archive::{Language, StudentFetcher};
+
+    #[tokio::main]
+    async fn main() -> anyhow::Result<()> {
+        let fetcher = StudentFetcher::new(Language::English).await?;
+        let aru = fetcher.get_student_by_name("Aru").unwrap();
+        let equipment = blue_archive::fetch_all_equipment(Language::English).await?;
+        let stats = aru
+            .stats()
+            .level(85)
+            .star_grade(5)
+            .weapon_level(30)
+            .equipment_tiers(&[7, 7, 7], &equipment)
+            .bond_level(20)
+            .breakdown();
+        println!("ATK: {} ({} from equipment)", stats.total.attack_power, stats.equipment.flat.attack_power);
+        Ok(())
+    }
+    ```
+*/
+#[derive(Debug, Clone)]
+pub struct StatCalculator<'a> {
+    student: &'a Student,
+    level: u32,
+    star_grade: u8,
+    weapon_level: Option<u32>,
+    equipment: Vec<&'a Equipment>,
+    bond_level: u32,
+    alt_bond_levels: Vec<(&'a Student, u32)>,
+    gear_tier: usize,
+}
+
+impl<'a> StatCalculator<'a> {
+    /// Creates a **[`StatCalculator`]** for a student at level 1, at the star grade they are obtained with and without a weapon.
+    pub fn new(student: &'a Student) -> Self {
+        Self {
+            student,
+            level: 1,
+            star_grade: student.stars.clamp(1, 5),
+            weapon_level: None,
+            equipment: vec![],
+            bond_level: 1,
+            alt_bond_levels: vec![],
+            gear_tier: 0,
+        }
+    }
+
+    /// Sets the level of the student.
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = level.max(1);
+        self
+    }
+
+    /// Sets the star grade of the student, which is kept between 1 and 5.
+    pub fn star_grade(mut self, star_grade: u8) -> Self {
+        self.star_grade = star_grade.clamp(1, 5);
+        self
+    }
+
+    /// Sets the level of the **[`Weapon`]** of the student, which only counts at 5 stars, where the weapon is unlocked.
+    pub fn weapon_level(mut self, weapon_level: u32) -> Self {
+        self.weapon_level = Some(weapon_level.max(1));
+        self
+    }
+
+    /**
+        Equips an **[`Equipment`]** at the highest level of its tier, replacing the previous one of the same category.
+
+        It only counts if its category is one of the slots of the student, in [`Student::equipment`].
+    */
+    pub fn equip(mut self, equipment: &'a Equipment) -> Self {
+        if self.slots().contains(&equipment.category) {
+            self.equipment
+                .retain(|piece| piece.category != equipment.category);
+            self.equipment.push(equipment);
+        }
+        self
+    }
+
+    /// Equips the **[`Equipment`]** of the given tier in each slot of the student, in order, out of the given equipment, where `0` leaves a slot empty.
+    pub fn equipment_tiers(mut self, tiers: &[u8], equipment: &'a [Equipment]) -> Self {
+        for (slot, tier) in self.slots().into_iter().zip(tiers) {
+            if let Some(piece) = equipment
+                .iter()
+                .find(|piece| piece.category == slot && piece.tier == *tier)
+            {
+                self = self.equip(piece);
+            }
+        }
+        self
+    }
+
+    /// Sets the bond level of the student.
+    pub fn bond_level(mut self, bond_level: u32) -> Self {
+        self.bond_level = bond_level.max(1);
+        self
+    }
+
+    /**
+        Sets the bond level of an alternate version of the student, such as a swimsuit version, which adds its own bond stats.
+
+        It only counts if the version is one of [`Student::favor_alts`].
+    */
+    pub fn alt_bond_level(mut self, alt: &'a Student, bond_level: u32) -> Self {
+        if self.student.favor_alts.contains(&alt.id.to_u32()) {
+            self.alt_bond_levels.retain(|(other, _)| other.id != alt.id);
+            self.alt_bond_levels.push((alt, bond_level.max(1)));
+        }
+        self
+    }
+
+    /// Sets the tier of the unique **[`Gear`][`super::student::Gear`]** of the student, where `0` is without it.
+    pub fn gear_tier(mut self, gear_tier: usize) -> Self {
+        self.gear_tier = gear_tier;
+        self
+    }
+
+    /// The stats of the student at its level and star grade, without the weapon.
+    pub fn base(&self) -> StudentStats {
+        let student = self.student;
+        let scale = LevelUpType::Standard.scale(self.level);
+        let with_star_grade = |stat: u32, bonuses: &[u32; 5]| {
+            raise(stat, bonuses[..usize::from(self.star_grade)].iter().sum())
+        };
+
+        StudentStats {
+            attack_power: with_star_grade(
+                interpolate(student.attack_power_1, student.attack_power_100, scale),
+                &STAR_GRADE_ATTACK_POWER,
+            ),
+            max_hp: with_star_grade(
+                interpolate(student.max_hp_1, student.max_hp_100, scale),
+                &STAR_GRADE_MAX_HP,
+            ),
+            defense_power: interpolate(student.defense_power_1, student.defense_power_100, scale),
+            heal_power: with_star_grade(
+                interpolate(student.heal_power_1, student.heal_power_100, scale),
+                &STAR_GRADE_HEAL_POWER,
+            ),
+        }
+    }
+
+    /// The stats of the **[`Weapon`]** at its level, which are empty when there is no weapon level or the student is not at 5 stars.
+    pub fn weapon(&self) -> StudentStats {
+        match self.weapon_level {
+            Some(weapon_level) if self.star_grade >= 5 => {
+                weapon_stats(&self.student.weapon, weapon_level)
+            }
+            _ => StudentStats::default(),
+        }
+    }
+
+    /// The stats of the equipped **[`Equipment`]**.
+    pub fn equipment(&self) -> StatBonus {
+        let mut bonus = StatBonus::default();
+        for piece in &self.equipment {
+            for (stat, values) in piece.stat_type.iter().zip(&piece.stat_value) {
+                bonus.add_stat(stat, values.last().copied().unwrap_or_default());
+            }
+        }
+        bonus
+    }
+
+    /// The stats of the bond of the student, and of its alternate versions.
+    pub fn bond(&self) -> StudentStats {
+        self.alt_bond_levels.iter().fold(
+            bond_stats(self.student, self.bond_level),
+            |stats, (alt, level)| stats + bond_stats(alt, *level),
+        )
+    }
+
+    /// The stats of the unique **[`Gear`][`super::student::Gear`]** at its tier.
+    pub fn gear(&self) -> StatBonus {
+        let mut bonus = StatBonus::default();
+        if let Some(gear) = self.student.gear() {
+            for (stat, values) in gear.stat_type.iter().zip(&gear.stat_value) {
+                let value = values.get(self.gear_tier).copied().unwrap_or_default();
+                bonus.add_stat(stat, u32::from(value));
+            }
+        }
+        bonus
+    }
+
+    /// The stats of the student by their source, alongside the final stats.
+    pub fn breakdown(&self) -> StatBlock {
+        let (base, weapon, equipment, bond, gear) = (
+            self.base(),
+            self.weapon(),
+            self.equipment(),
+            self.bond(),
+            self.gear(),
+        );
+        let flat = base + weapon + bond + equipment.flat + gear.flat;
+        let coefficient = equipment.coefficient + gear.coefficient;
+        StatBlock {
+            base,
+            weapon,
+            equipment,
+            bond,
+            gear,
+            total: StudentStats {
+                attack_power: raise(flat.attack_power, coefficient.attack_power),
+                max_hp: raise(flat.max_hp, coefficient.max_hp),
+                defense_power: raise(flat.defense_power, coefficient.defense_power),
+                heal_power: raise(flat.heal_power, coefficient.heal_power),
+            },
+        }
+    }
+
+    /// The final stats of the student.
+    pub fn calculate(&self) -> StudentStats {
+        self.breakdown().total
+    }
+
+    /// The categories of the equipment slots of the student.
+    fn slots(&self) -> Vec<EquipmentCategory> {
+        self.student
+            .equipment
+            .iter()
+            .filter_map(|slot| EquipmentCategory::from_str(slot).ok())
+            .collect()
+    }
+}
+
+impl Student {
+    /// Starts a **[`StatCalculator`]** for the student, see [`StatCalculator::new`].
+    pub fn stats(&self) -> StatCalculator<'_> {
+        StatCalculator::new(self)
+    }
+}
+
+impl LevelUpType {
+    /**
+        How far along a stat is at a level, between its value at level 1 (`0.0`) and level 100 (`1.0`), rounded to 4 decimals.
+
+        A [`LevelUpType::Standard`] stat grows evenly, a [`LevelUpType::Premature`] one grows quickly at first and slowly after,
+        and a [`LevelUpType::LateBloom`] one grows slowly at first and quickly after. Past level 100, every stat grows evenly.
+    */
+    pub fn scale(&self, level: u32) -> f64 {
+        let progress = f64::from(level.saturating_sub(1)) / 99.0;
+        let scale = match self {
+            _ if progress >= 1.0 => progress,
+            // The curves of the `StatLevelUpType` of weapons in ``SchaleDB``, where `progress` is `(level - 1) / 99`.
+            Self::Premature => progress * (2.0 - progress),
+            Self::LateBloom => progress * progress,
+            Self::Standard | Self::Unknown => progress,
+        };
+        (scale * 10_000.0).round() / 10_000.0
+    }
+}
+
+/**
+    The stats of the bond of a **[`Student`]** at a level.
+
+    Each level past the first adds the values of an entry of [`Student::favor_stat_value`],
+    moving on to the next entry every five levels up to level 20, and every ten levels up to level 50.
+*/
+fn bond_stats(student: &Student, level: u32) -> StudentStats {
+    let mut stats = StudentStats::default();
+    for level in 1..level.min(50) {
+        let index = match level {
+            ..20 => level / 5,
+            _ => level / 10 + 2,
+        };
+        let Some(values) = student.favor_stat_value.get(index as usize) else {
+            continue;
+        };
+        for (stat, value) in student.favor_stat_type.iter().zip(values) {
+            stats.add_stat(stat, u32::from(*value));
+        }
+    }
+    stats
+}
+
+/// The stats of a **[`Weapon`]** at a level.
+fn weapon_stats(weapon: &Weapon, level: u32) -> StudentStats {
+    let scale = weapon.stat_level_up_type.scale(level);
+    StudentStats {
+        attack_power: interpolate(weapon.attack_power_1, weapon.attack_power_100, scale),
+        max_hp: interpolate(weapon.max_hp_1, weapon.max_hp_100, scale),
+        defense_power: 0,
+        heal_power: interpolate(weapon.heal_power_1, weapon.heal_power_100, scale),
+    }
+}
+
+/// Turns a widened stat back into a `u32`, saturating at [`u32::MAX`] for levels that are far beyond the cap.
+fn saturate(stat: u64) -> u32 {
+    u32::try_from(stat).unwrap_or(u32::MAX)
+}
+
+/// Raises a stat by a rate in ten-thousandths, rounded to the closest whole number, such as the bonus of a star grade.
+fn raise(stat: u32, rate: u32) -> u32 {
+    saturate((u64::from(stat) * (10_000 + u64::from(rate)) + 5_000) / 10_000)
+}
+
+/// The value of a stat between its value at level 1 and level 100, rounded to the closest whole number.
+fn interpolate(at_1: u32, at_100: u32, scale: f64) -> u32 {
+    let stat = f64::from(at_1) + (f64::from(at_100) - f64::from(at_1)) * scale;
+    stat.round().max(0.0) as u32
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     enums::*,
     serialization,
-    types::{Age, Effect, Released, SkillKind, ID},
+    types::{description::DescriptionRenderer, Age, Effect, Released, SkillKind, ID},
     IMAGE_DATA_URI,
 };
 
@@ -42,8 +42,8 @@ pub struct Student {
         deserialize_with = "serialization::deserialize_html_encoded_string"
     )]
     pub description: String,
-    school: String,
-    club: String,
+    pub(crate) school: String,
+    pub(crate) club: String,
     /// The amount of stars a [`Student`] is rated.
     #[serde(alias = "StarGrade")]
     pub stars: u8,
@@ -58,8 +58,9 @@ pub struct Student {
     pub indoor_battle_adaptation: u8,  // todo
     weapon_type: String,
     weapon_img: String,
-    pub cover: bool,            // todo
-    pub equipment: Vec<String>, // todo
+    pub cover: bool, // todo
+    /// The categories of the equipment slots, such as `Hat`, see [`StatCalculator::equip`][`super::StatCalculator::equip`].
+    pub equipment: Vec<String>,
     #[serde(alias = "CollectionBG")]
     collection_bg: String,
     collection_texture: Option<String>,
@@ -103,10 +104,13 @@ pub struct Student {
     pub regen_cost: u16,
     /// Contains a collection of **[`Skills`][`Skill`]**.
     pub skills: Vec<Skill>,
-    pub favor_stat_type: Vec<String>,   // todo
-    pub favor_stat_value: Vec<Vec<u8>>, // todo
-    pub favor_alts: Vec<u32>,           // todo
-    pub memory_lobby: Vec<u8>,          // todo
+    /// The stats that the bond raises, such as `AttackPower`.
+    pub favor_stat_type: Vec<String>,
+    /// How much each bond level raises the stats of [`Student::favor_stat_type`] by, see [`StatCalculator::bond_level`][`super::StatCalculator::bond_level`].
+    pub favor_stat_value: Vec<Vec<u8>>,
+    /// The ids of the alternate versions of the student, whose bond also raises the stats of the student.
+    pub favor_alts: Vec<u32>,
+    pub memory_lobby: Vec<u8>, // todo
     /// The name of the music in the students' recollection lobby.
     #[serde(alias = "MemoryLobbyBGM")]
     pub memory_lobby_bgm: String,
@@ -169,6 +173,14 @@ impl Student {
         self.gear.get()
     }
 
+    /// Builds the urls of the **[`Student`]** and its **[`Gear`]** relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, image_uri: &str) {
+        self.image = StudentImageData::with_image_uri(self, image_uri);
+        if let GearKind::Present(gear) = &mut self.gear {
+            gear.image_uri = image_uri.to_string();
+        }
+    }
+
     /// Gets the **[`School`]** of the student.
     pub fn school(&self) -> School {
         School::from_str(&self.school).unwrap_or(School::Unknown(self.school.clone()))
@@ -242,6 +254,31 @@ pub struct Skill {
     pub effects: Vec<Effect>,
 }
 
+impl Skill {
+    /**
+        Renders the description of the skill as plain text at a level, where `0` is the first, see **[`DescriptionRenderer`]**.
+
+        Returns [`None`] if the skill has no description, or if a placeholder of the description, such as `<?1>`,
+        has no value at `level`. `level` starts at `0`, unlike the level of a skill in the game, which starts at `1`.
+    */
+    pub fn render_description(&self, level: usize) -> Option<String> {
+        self.render_description_with(level, &DescriptionRenderer::default())
+    }
+
+    /// Renders the description of the skill at a level, where `0` is the first, with a **[`DescriptionRenderer`]**.
+    pub fn render_description_with(
+        &self,
+        level: usize,
+        renderer: &DescriptionRenderer,
+    ) -> Option<String> {
+        renderer.render(
+            self.desc.as_deref()?,
+            self.parameters.as_deref().unwrap_or_default(),
+            level,
+        )
+    }
+}
+
 /// A [`Student`] specific summon.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -286,11 +323,13 @@ pub struct Gear {
     icon: String,
     pub tier_up_material: Vec<Vec<u16>>,
     pub tier_up_material_amount: Vec<Vec<u8>>,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
 }
 impl Gear {
     /// Returns the url of a gear icon.
     pub fn icon_url(&self) -> String {
-        format!("{IMAGE_DATA_URI}/gear/{}", self.icon)
+        format!("{}/gear/{}", self.image_uri, self.icon)
     }
 }
 /// There is an issue where Gear in data is represented as `"gear": {}`, therefore this is a mitigation against that.
@@ -342,17 +381,22 @@ pub struct StudentImageData {
 impl StudentImageData {
     /// Creates itself from a given **[`Student`]**.
     pub fn new(student: &Student) -> Self {
+        Self::with_image_uri(student, IMAGE_DATA_URI)
+    }
+
+    /// Creates itself from a given **[`Student`]**, with the urls being relative to the given `image_uri`.
+    pub fn with_image_uri(student: &Student, image_uri: &str) -> Self {
         Self {
             portrait: Portrait {
-                full_body_url: format!("{IMAGE_DATA_URI}/student/portrait/{}.webp", student.id.0),
-                icon_url: format!("{IMAGE_DATA_URI}/student/icon/{}.webp", student.id.0),
+                full_body_url: format!("{image_uri}/student/portrait/{}.webp", student.id.0),
+                icon_url: format!("{image_uri}/student/icon/{}.webp", student.id.0),
                 alternative_full_body_url: format!(
-                    "{IMAGE_DATA_URI}/student/portrait/{}_2.webp",
+                    "{image_uri}/student/portrait/{}_2.webp",
                     student.id.0
                 ),
-                bg_url: format!("{IMAGE_DATA_URI}/background/{}.jpg", student.collection_bg),
+                bg_url: format!("{image_uri}/background/{}.jpg", student.collection_bg),
             },
-            weapon_icon_url: format!("{IMAGE_DATA_URI}/weapon/{}.webp", student.weapon_img),
+            weapon_icon_url: format!("{image_uri}/weapon/{}.webp", student.weapon_img),
         }
     }
 }
@@ -4,9 +4,9 @@ use serde::{Deserialize, Serialize};
 
 use std::str::FromStr;
 
-use crate::{serialization, Armor, BulletType, WeaponType, IMAGE_DATA_URI};
+use crate::{serialization, Armor, BulletType, WeaponType};
 
-use super::{Effect, Radius, ID};
+use super::{description::DescriptionRenderer, Effect, Radius, ID};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "PascalCase")]
@@ -67,6 +67,17 @@ impl Summon {
             None => WeaponType::None,
         }
     }
+
+    /// Builds the urls of the skills of this summon relative to the given `image_uri`.
+    pub(crate) fn set_image_uri(&mut self, image_uri: &str) {
+        for skill in &mut self.skills {
+            match skill {
+                Skill::Normal(skill) => skill.image_uri = image_uri.to_string(),
+                Skill::Passive(skill) => skill.image_uri = image_uri.to_string(),
+                Skill::AutoAttack { .. } | Skill::Unknown => (),
+            }
+        }
+    }
 }
 
 /// **[`Summon`] specific Skills**.
@@ -82,6 +93,9 @@ pub enum Skill {
     Normal(NormalSkill),
     #[serde(alias = "passive")]
     Passive(PassiveSkill),
+    /// A skill of a type that is not known, whose fields are not kept.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -101,6 +115,8 @@ pub struct NormalSkill {
     icon: String,
     is_summon_skill: bool,
     pub effects: Option<Vec<Effect>>,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -118,18 +134,58 @@ pub struct PassiveSkill {
     icon: String,
     pub is_summon_skill: bool,
     pub effects: Option<Vec<Effect>>,
+    #[serde(skip, default = "serialization::default_image_uri")]
+    image_uri: String,
 }
 
 impl NormalSkill {
     /** Gets the icon of this skill represented in a `URI`. */
     pub fn icon(&self) -> String {
-        format!("{IMAGE_DATA_URI}/skill/{}.webp", self.icon)
+        format!("{}/skill/{}.webp", self.image_uri, self.icon)
+    }
+
+    /**
+        Renders the description of this skill as plain text at a level, where `0` is the first, see **[`DescriptionRenderer`]**.
+
+        Returns [`None`] if a placeholder of the description, such as `<?1>`, has no value at `level`.
+        `level` starts at `0`, unlike the level of a skill in the game, which starts at `1`.
+    */
+    pub fn render_description(&self, level: usize) -> Option<String> {
+        self.render_description_with(level, &DescriptionRenderer::default())
+    }
+
+    /** Renders the description of this skill at a level, where `0` is the first, with a **[`DescriptionRenderer`]**. */
+    pub fn render_description_with(
+        &self,
+        level: usize,
+        renderer: &DescriptionRenderer,
+    ) -> Option<String> {
+        renderer.render(&self.description, &self.parameters, level)
     }
 }
 
 impl PassiveSkill {
     /** Gets the icon of this skill represented in a `URI`. */
     pub fn icon(&self) -> String {
-        format!("{IMAGE_DATA_URI}/skill/{}.webp", self.icon)
+        format!("{}/skill/{}.webp", self.image_uri, self.icon)
+    }
+
+    /**
+        Renders the description of this skill as plain text at a level, where `0` is the first, see **[`DescriptionRenderer`]**.
+
+        Returns [`None`] if a placeholder of the description, such as `<?1>`, has no value at `level`.
+        `level` starts at `0`, unlike the level of a skill in the game, which starts at `1`.
+    */
+    pub fn render_description(&self, level: usize) -> Option<String> {
+        self.render_description_with(level, &DescriptionRenderer::default())
+    }
+
+    /** Renders the description of this skill at a level, where `0` is the first, with a **[`DescriptionRenderer`]**. */
+    pub fn render_description_with(
+        &self,
+        level: usize,
+        renderer: &DescriptionRenderer,
+    ) -> Option<String> {
+        renderer.render(&self.description, &self.parameters, level)
     }
 }
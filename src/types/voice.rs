@@ -0,0 +1,140 @@
+//! Contains the [`VoiceData`] structure and its respective structures.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+use crate::serialization;
+
+use super::{Student, ID};
+
+/// Contains the voice lines of every **[`Student`]**, keyed by their **[`ID`]**.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(transparent)]
+pub struct VoiceData {
+    pub students: HashMap<ID, StudentVoice>,
+}
+
+impl VoiceData {
+    /// Gets the voice lines of a **[`Student`]** by their **[`ID`]**.
+    pub fn get(&self, id: &ID) -> Option<&StudentVoice> {
+        self.students.get(id)
+    }
+
+    /// Gets the voice lines of a **[`Student`]**.
+    pub fn of(&self, student: &Student) -> Option<&StudentVoice> {
+        self.get(&student.id)
+    }
+
+    /// Builds the audio urls of every voice line relative to the given `voice_uri`.
+    pub(crate) fn set_voice_uri(&mut self, voice_uri: &str) {
+        for line in self
+            .students
+            .values_mut()
+            .flat_map(|voice| voice.lines_mut())
+        {
+            line.voice_uri = voice_uri.to_string();
+        }
+    }
+}
+
+/// The category of a **[`VoiceLine`]**, which decides when it is played.
+#[derive(Debug, Display, EnumIter, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum VoiceCategory {
+    /// Played in menus, such as when the student is selected in a formation.
+    Normal,
+    /// Played in the lobby or when the student is touched.
+    Lobby,
+    /// Played during events, such as a new year or a birthday.
+    Event,
+    /// Played during battle, such as when the student uses their skill.
+    Battle,
+}
+
+/// The voice lines of a single **[`Student`]**, split by their **[`VoiceCategory`]**.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StudentVoice {
+    #[serde(default)]
+    pub normal: Vec<VoiceLine>,
+    #[serde(default)]
+    pub lobby: Vec<VoiceLine>,
+    #[serde(default)]
+    pub event: Vec<VoiceLine>,
+    #[serde(default)]
+    pub battle: Vec<VoiceLine>,
+}
+
+impl StudentVoice {
+    /// Gets the voice lines of a **[`VoiceCategory`]**.
+    pub fn category(&self, category: VoiceCategory) -> &[VoiceLine] {
+        match category {
+            VoiceCategory::Normal => &self.normal,
+            VoiceCategory::Lobby => &self.lobby,
+            VoiceCategory::Event => &self.event,
+            VoiceCategory::Battle => &self.battle,
+        }
+    }
+
+    /// Iterates over every voice line alongside its **[`VoiceCategory`]**.
+    pub fn lines(&self) -> impl Iterator<Item = (VoiceCategory, &VoiceLine)> {
+        [
+            VoiceCategory::Normal,
+            VoiceCategory::Lobby,
+            VoiceCategory::Event,
+            VoiceCategory::Battle,
+        ]
+        .into_iter()
+        .flat_map(move |category| {
+            self.category(category)
+                .iter()
+                .map(move |line| (category, line))
+        })
+    }
+
+    fn lines_mut(&mut self) -> impl Iterator<Item = &mut VoiceLine> {
+        self.normal
+            .iter_mut()
+            .chain(self.lobby.iter_mut())
+            .chain(self.event.iter_mut())
+            .chain(self.battle.iter_mut())
+    }
+}
+
+/// **A voice line of a [`Student`]**, which can be made up of multiple audio clips.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoiceLine {
+    /// The group that the voice line belongs to, e.g. `Formation_Select`.
+    pub group: String,
+    /// The names of the audio clips, which are relative to the root of the voice lines.
+    #[serde(
+        alias = "AudioClip",
+        deserialize_with = "serialization::deserialize_one_or_many"
+    )]
+    pub audio_clips: Vec<String>,
+    /// What is said in the voice line, if it has been transcribed.
+    #[serde(default)]
+    transcription: Option<String>,
+    #[serde(skip, default = "serialization::default_voice_uri")]
+    voice_uri: String,
+}
+
+impl VoiceLine {
+    /// What is said in the voice line, if it has been transcribed.
+    pub fn transcription(&self) -> Option<String> {
+        self.transcription
+            .as_ref()
+            .filter(|transcription| !transcription.is_empty())
+            .map(|transcription| html_escape::decode_html_entities(transcription).into())
+    }
+
+    /// Returns the urls of the audio clips of the voice line.
+    pub fn audio_urls(&self) -> Vec<String> {
+        self.audio_clips
+            .iter()
+            .map(|clip| format!("{}/{clip}.ogg", self.voice_uri))
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+use blue_archive::{
+    audit::{audit_endpoint, Finding, AUDITED_ENDPOINTS},
+    source::{Endpoint, MemorySource},
+    BlueArchiveClient, Language,
+};
+
+const ENEMIES: &str = r#"[
+    {
+        "Id": 7, "DevName": "Yoheki_Sniper", "Name": "Mercenary Sniper",
+        "SquadType": "Main", "Rank": "Minion", "BulletType": "Sonic", "ArmorType": "LightArmor",
+        "WeaponType": "SR", "Size": "Small", "Icon": null, "StabilityPoint": 0, "StabilityRate": 0,
+        "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 3, "MaxHP100": 4,
+        "DefensePower1": 5, "DefensePower100": 6, "HealPower1": 7, "HealPower100": 8,
+        "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0, "CriticalDamageRate": 0,
+        "CriticalResistPoint": 0, "CriticalDamageResistRate": 0, "Range": 0, "DamagedRatio": 0,
+        "Transcendence": [1, 2]
+    }
+]"#;
+
+const CURRENCIES: &str = r#"[
+    { "Id": 1, "Category": "Currency", "Rarity": "R", "Icon": "", "Name": "Credits", "Desc": "" }
+]"#;
+
+#[test]
+fn audit_reports_unknown_values_and_ignored_keys() {
+    let audit = audit_endpoint(Endpoint::Enemies, Language::English, ENEMIES.as_bytes()).unwrap();
+
+    assert_eq!(audit.entities.len(), 1);
+    let entity = &audit.entities[0];
+    assert_eq!(entity.id, Some(7));
+    assert_eq!(entity.name.as_deref(), Some("Mercenary Sniper"));
+    assert!(entity.findings.contains(&Finding::IgnoredKey {
+        path: "[0].Transcendence".to_string()
+    }));
+    assert!(entity.findings.contains(&Finding::UnknownValue {
+        path: "[0].BulletType".to_string(),
+        kind: "BulletType",
+        value: "Sonic".to_string()
+    }));
+}
+
+#[test]
+fn audit_clean_data() {
+    let audit =
+        audit_endpoint(Endpoint::Currency, Language::English, CURRENCIES.as_bytes()).unwrap();
+    assert!(audit.is_clean());
+}
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+const CONFIG: &str = r#"{
+    "Links": [],
+    "Regions": [
+        {
+            "Name": "Jp", "StudentMaxLevel": 90, "Events": [801],
+            "CurrentRaid": [{ "type": "Raid", "raid": 1, "terrain": "Outdoor", "start": 1700000000, "end": 1700500000 }]
+        }
+    ]
+}"#;
+
+const LOCALIZATION: &str =
+    r#"{ "StatName": { "AttackPower": "ATK" }, "School": { "Gehenna": "Gehenna" } }"#;
+
+const VOICE: &str = r#"{
+    "10000": { "Normal": [{ "Group": "Formation_Select", "AudioClip": "JP_Aru/Aru_Formation_Select" }] }
+}"#;
+
+const CLEAN_ENEMIES: &str = r#"[
+    {
+        "Id": 101, "DevName": "Grunt", "Name": "Grunt",
+        "SquadType": "Main", "Rank": "Minion", "BulletType": "Explosion", "ArmorType": "LightArmor",
+        "WeaponType": "SMG", "Size": "Medium", "Icon": null, "StabilityPoint": 0, "StabilityRate": 0,
+        "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 3, "MaxHP100": 4,
+        "DefensePower1": 5, "DefensePower100": 6, "HealPower1": 7, "HealPower100": 8,
+        "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0, "CriticalDamageRate": 0,
+        "CriticalResistPoint": 0, "CriticalDamageResistRate": 0, "Range": 0, "DamagedRatio": 0
+    }
+]"#;
+
+const EQUIPMENT: &str = r#"[
+    {
+        "Id": 2000, "Name": "T2 Hat", "IsReleased": [true, true, true], "Desc": "",
+        "Category": "Hat", "Rarity": "N", "Tier": 2, "Icon": "equipment_2000",
+        "Shops": [], "StatType": [], "StatValue": [], "Recipe": null, "RecipeCost": null
+    }
+]"#;
+
+const EVENTS: &str = r#"[{ "Id": 802, "Name": "Rerun", "IsReleased": [true, false, false] }]"#;
+
+const FURNITURE: &str = r#"[
+    {
+        "Id": 3, "IsReleased": [true, true, true], "Category": "Furnitures", "SubCategory": "Table",
+        "Rarity": "R", "Icon": "Lobby_Table_3", "Name": "Office Desk"
+    }
+]"#;
+
+const ITEMS: &str = r#"[
+    {
+        "Id": 2000, "IsReleased": [true, true, true], "Category": "Material", "SubCategory": "Artifact",
+        "Rarity": "N", "Icon": "item_icon_artifact_0", "Name": "Nebra Disk Fragment"
+    }
+]"#;
+
+const RAIDS: &str = r#"{
+    "Raid": [],
+    "TimeAttack": [
+        {
+            "Id": 1, "IsReleased": [true, true, true], "DungeonType": "Shooting", "Icon": "TimeAttack_Shooting",
+            "Terrain": "Street", "BulletType": "Explosion", "ArmorType": "LightArmor",
+            "Restrictions": [{ "Property": "ArmorType", "Operand": "Equal", "Value": "LightArmor" }],
+            "EnemyLevel": [10], "Formations": [{ "Id": 1001, "Level": [10], "EnemyList": [101] }], "Rules": [[1]]
+        }
+    ],
+    "TimeAttackRules": [
+        { "Id": 1, "Name": "Fatigue", "Icon": "rule_fatigue", "Desc": "Reduces healing by <?1>.", "Parameters": [["10%"]] }
+    ]
+}"#;
+
+const STAGES: &str = r#"{
+    "WeekDungeon": [
+        { "Id": 3010101, "Type": "ChaserA", "Stage": 1, "EntryCost": [[8, 1]], "Terrain": "Street",
+          "Rewards": { "Default": [{ "Type": "Currency", "Id": 1, "Amount": 2000 }] } }
+    ]
+}"#;
+
+fn summons(skill_type: &str) -> String {
+    format!(
+        r#"[
+            {{
+                "Id": 99990, "Name": "Drone", "DevName": "Drone", "Type": "Summon", "TacticRole": null,
+                "BulletType": "Explosion", "ArmorType": "LightArmor", "WeaponType": null, "StabilityPoint": 0,
+                "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 1, "MaxHP100": 2, "DefensePower1": 0, "DefensePower100": 0,
+                "HealPower1": 0, "HealPower100": 0, "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0,
+                "CriticalDamageRate": 0, "AmmoCount": 0, "AmmoCost": 0, "Range": 0, "MoveSpeed": 0, "RegenCost": 0,
+                "StreetBattleAdaptation": 2, "OutdoorBattleAdaptation": 2, "IndoorBattleAdaptation": 2,
+                "Skills": [{{ "SkillType": "{skill_type}", "Effects": [] }}]
+            }}
+        ]"#
+    )
+}
+
+/// A source with the data of every audited endpoint, with the given summons.
+fn source(summons: String) -> MemorySource {
+    MemorySource::new()
+        .with(Endpoint::Config, Language::English, CONFIG)
+        .with(Endpoint::Localization, Language::English, LOCALIZATION)
+        .with(Endpoint::Voice, Language::English, VOICE)
+        .with(Endpoint::Students, Language::English, STUDENTS)
+        .with(Endpoint::Enemies, Language::English, CLEAN_ENEMIES)
+        .with(Endpoint::Equipment, Language::English, EQUIPMENT)
+        .with(Endpoint::Events, Language::English, EVENTS)
+        .with(Endpoint::Furniture, Language::English, FURNITURE)
+        .with(Endpoint::Items, Language::English, ITEMS)
+        .with(Endpoint::Currency, Language::English, CURRENCIES)
+        .with(Endpoint::Raids, Language::English, RAIDS)
+        .with(Endpoint::Stages, Language::English, STAGES)
+        .with(Endpoint::Summons, Language::English, summons)
+}
+
+#[tokio::test]
+async fn audit_every_endpoint() {
+    let client = BlueArchiveClient::builder()
+        .source(source(summons("autoattack")))
+        .build();
+    let report = client.fetch_audit().await;
+
+    assert_eq!(report.endpoints.len(), AUDITED_ENDPOINTS.len());
+    assert!(report.failures.is_empty());
+    assert!(
+        report.is_clean(),
+        "{:?}",
+        report.findings().collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn audit_reports_unknown_summon_skill() {
+    let client = BlueArchiveClient::builder()
+        .source(source(summons("Overdrive")))
+        .build();
+    let report = client.fetch_audit().await;
+
+    let findings = report.findings().collect::<Vec<_>>();
+    assert_eq!(findings.len(), 1);
+    let (endpoint, entity, finding) = findings[0];
+    assert_eq!(endpoint, Endpoint::Summons);
+    assert_eq!(entity.id, Some(99990));
+    assert_eq!(
+        *finding,
+        Finding::UnknownValue {
+            path: "[0].Skills[0].SkillType".to_string(),
+            kind: "SkillType",
+            value: "Overdrive".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn audit_keeps_going_past_missing_endpoints() {
+    let source = MemorySource::new()
+        .with(Endpoint::Currency, Language::English, CURRENCIES)
+        .with(Endpoint::Enemies, Language::English, ENEMIES);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let report = client.fetch_audit().await;
+
+    assert!(!report.is_clean());
+    assert_eq!(report.endpoints.len(), 2);
+    assert_eq!(report.failures.len(), AUDITED_ENDPOINTS.len() - 2);
+    assert!(report
+        .failures
+        .iter()
+        .all(|failure| failure.endpoint != Endpoint::Currency));
+    assert!(report
+        .findings()
+        .any(|(endpoint, ..)| endpoint == Endpoint::Enemies));
+}
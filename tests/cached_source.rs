@@ -0,0 +1,211 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use blue_archive::{
+    source::{cache::Freshness, CachedSource, DataSource, Endpoint},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+/// A source that counts its fetches, and can be made to fail.
+#[derive(Default)]
+struct Flaky {
+    fetches: AtomicUsize,
+    failing: AtomicBool,
+}
+
+impl DataSource for Flaky {
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        language: Language,
+    ) -> Result<Arc<[u8]>, BlueArchiveError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        match self.failing.load(Ordering::SeqCst) {
+            true => Err(BlueArchiveError::NotFound { endpoint, language }),
+            false => Ok(Arc::from(b"[]".as_slice())),
+        }
+    }
+}
+
+/// A source that takes a while to fetch, counting its fetches.
+#[derive(Default)]
+struct Slow {
+    fetches: AtomicUsize,
+}
+
+impl DataSource for Slow {
+    async fn fetch(&self, _: Endpoint, _: Language) -> Result<Arc<[u8]>, BlueArchiveError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        Ok(Arc::from(b"[]".as_slice()))
+    }
+}
+
+#[tokio::test]
+async fn serve_cached_within_ttl() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Flaky::default(), directory.path());
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    client.fetch_all_enemies().await.unwrap();
+    let source = client.source();
+    assert!(matches!(
+        source.freshness(Endpoint::Enemies, Language::English),
+        Some(Freshness::Fetched)
+    ));
+
+    client.fetch_all_enemies().await.unwrap();
+    assert!(matches!(
+        source.freshness(Endpoint::Enemies, Language::English),
+        Some(Freshness::Cached { .. })
+    ));
+    assert_eq!(source.inner().fetches.load(Ordering::SeqCst), 1);
+    assert_eq!(source.entries().len(), 1);
+}
+
+#[tokio::test]
+async fn serve_stale_when_source_fails() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Flaky::default(), directory.path()).with_ttl(Duration::ZERO);
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    client.fetch_all_enemies().await.unwrap();
+    client
+        .source()
+        .inner()
+        .failing
+        .store(true, Ordering::SeqCst);
+    std::thread::sleep(Duration::from_millis(10));
+
+    assert!(client.fetch_all_enemies().await.unwrap().is_empty());
+    let freshness = client
+        .source()
+        .freshness(Endpoint::Enemies, Language::English)
+        .unwrap();
+    assert!(matches!(freshness, Freshness::Stale { .. }));
+    assert!(freshness.is_stale());
+}
+
+#[tokio::test]
+async fn purge_cached_data() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Flaky::default(), directory.path());
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    client.fetch_all_enemies().await.unwrap();
+    assert_eq!(client.source().purge_all().unwrap(), 1);
+    assert!(client
+        .source()
+        .entry(Endpoint::Enemies, Language::English)
+        .is_none());
+
+    client
+        .source()
+        .inner()
+        .failing
+        .store(true, Ordering::SeqCst);
+    assert!(client.fetch_all_enemies().await.is_err());
+}
+
+#[tokio::test]
+async fn write_concurrent_fetches_apart() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Flaky::default(), directory.path()).with_ttl(Duration::ZERO);
+
+    let fetches = (0..8)
+        .map(|_| {
+            let source = source.clone();
+            tokio::spawn(async move { source.fetch(Endpoint::Enemies, Language::English).await })
+        })
+        .collect::<Vec<_>>();
+    for fetch in fetches {
+        assert_eq!(*fetch.await.unwrap().unwrap(), *b"[]");
+    }
+
+    let path = source.file_path(Endpoint::Enemies, Language::English);
+    let files = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    assert_eq!(files, vec![path.clone()]);
+    assert_eq!(std::fs::read(path).unwrap(), b"[]");
+}
+
+#[tokio::test]
+async fn revalidate_once_at_a_time() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Slow::default(), directory.path())
+        .with_ttl(Duration::ZERO)
+        .with_stale_while_revalidate(Duration::from_secs(60 * 60));
+
+    source
+        .fetch(Endpoint::Enemies, Language::English)
+        .await
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    for _ in 0..5 {
+        source
+            .fetch(Endpoint::Enemies, Language::English)
+            .await
+            .unwrap();
+        assert!(matches!(
+            source.freshness(Endpoint::Enemies, Language::English),
+            Some(Freshness::Revalidating { .. })
+        ));
+    }
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(matches!(
+        source.freshness(Endpoint::Enemies, Language::English),
+        Some(Freshness::Fetched)
+    ));
+    assert_eq!(source.inner().fetches.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn serve_fetched_data_when_cache_is_not_writable() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let source = CachedSource::new(Flaky::default(), file.path());
+
+    assert_eq!(
+        *source
+            .fetch(Endpoint::Enemies, Language::English)
+            .await
+            .unwrap(),
+        *b"[]"
+    );
+    assert!(matches!(
+        source.freshness(Endpoint::Enemies, Language::English),
+        Some(Freshness::Uncached { .. })
+    ));
+}
+
+#[tokio::test]
+async fn record_failed_revalidation_as_stale() {
+    let directory = tempfile::tempdir().unwrap();
+    let source = CachedSource::new(Flaky::default(), directory.path())
+        .with_ttl(Duration::ZERO)
+        .with_stale_while_revalidate(Duration::from_secs(60 * 60));
+
+    source
+        .fetch(Endpoint::Enemies, Language::English)
+        .await
+        .unwrap();
+    source.inner().failing.store(true, Ordering::SeqCst);
+    std::thread::sleep(Duration::from_millis(10));
+    source
+        .fetch(Endpoint::Enemies, Language::English)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(matches!(
+        source.freshness(Endpoint::Enemies, Language::English),
+        Some(Freshness::Stale { .. })
+    ));
+}
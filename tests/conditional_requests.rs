@@ -0,0 +1,136 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use blue_archive::{
+    source::{DataSource, Endpoint, HttpSource},
+    BlueArchiveClient, Language,
+};
+
+const CURRENCIES: &str = r#"[]"#;
+
+/// Starts a stand-in for the data server, which answers `If-None-Match: "v1"` with `304 Not Modified`.
+///
+/// Returns the data root to use, and the `If-None-Match` header of every request that was served.
+fn serve(requests: usize) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let data_uri = format!("http://{}/data", listener.local_addr().unwrap());
+    let seen = Arc::new(Mutex::new(vec![]));
+
+    let served = seen.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().take(requests) {
+            let mut stream = stream.unwrap();
+            let if_none_match = BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty())
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("if-none-match")
+                        .then(|| value.trim().to_string())
+                });
+
+            let response = match if_none_match.as_deref() {
+                Some("\"v1\"") => {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n"
+                        .to_string()
+                }
+                _ => format!(
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{CURRENCIES}",
+                    CURRENCIES.len()
+                ),
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+            served.lock().unwrap().push(if_none_match);
+        }
+    });
+
+    (data_uri, seen)
+}
+
+#[tokio::test]
+async fn reuse_data_when_not_modified() {
+    let (data_uri, seen) = serve(2);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .language(Language::English)
+        .build();
+
+    assert!(client.fetch_all_currencies().await.unwrap().is_empty());
+    assert!(client.fetch_all_currencies().await.unwrap().is_empty());
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![None, Some("\"v1\"".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn share_remembered_body_when_not_modified() {
+    let (data_uri, _) = serve(2);
+    let source = HttpSource::new().with_data_uri(data_uri);
+
+    let fetched = source
+        .fetch(Endpoint::Currency, Language::English)
+        .await
+        .unwrap();
+    let reused = source
+        .fetch(Endpoint::Currency, Language::English)
+        .await
+        .unwrap();
+    assert!(Arc::ptr_eq(&fetched, &reused));
+}
+
+#[tokio::test]
+async fn skip_validators_when_disabled() {
+    let (data_uri, seen) = serve(2);
+    let source = HttpSource::new()
+        .with_data_uri(data_uri)
+        .with_conditional_requests(false);
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    client.fetch_all_currencies().await.unwrap();
+    client.fetch_all_currencies().await.unwrap();
+
+    assert_eq!(*seen.lock().unwrap(), vec![None, None]);
+}
+
+#[tokio::test]
+async fn bound_remembered_bodies() {
+    let (data_uri, seen) = serve(3);
+    let source = HttpSource::new().with_data_uri(data_uri);
+    let client = BlueArchiveClient::builder().source(source.clone()).build();
+
+    client.fetch_all_currencies().await.unwrap();
+    assert_eq!(source.remembered_bytes(), CURRENCIES.len());
+
+    let source = source.with_remembered_bytes(CURRENCIES.len() - 1);
+    source.clear_validators();
+    let client = BlueArchiveClient::builder().source(source.clone()).build();
+    client.fetch_all_currencies().await.unwrap();
+    client.fetch_all_currencies().await.unwrap();
+
+    assert_eq!(source.remembered_bytes(), 0);
+    assert_eq!(*seen.lock().unwrap(), vec![None, None, None]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn reuse_data_when_not_modified_blocking() {
+    let (data_uri, seen) = serve(2);
+    let client = blue_archive::blocking::BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .build();
+
+    assert!(client.get_all_currencies().unwrap().is_empty());
+    assert!(client.get_all_currencies().unwrap().is_empty());
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![None, Some("\"v1\"".to_string())]
+    );
+}
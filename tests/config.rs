@@ -0,0 +1,105 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    BlueArchiveClient, Language, Region, Terrain,
+};
+use chrono::{TimeZone, Utc};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+const CONFIG: &str = r#"{
+    "Links": [],
+    "Regions": [
+        {
+            "Name": "Jp", "StudentMaxLevel": 90, "WeaponMaxLevel": 60, "BondMaxLevel": 100,
+            "EquipmentMaxLevel": [10, 10, 10], "CampaignMax": 26, "Events": [701, 801],
+            "CurrentGacha": [{ "characters": [10005], "start": 1700000000, "end": 1700600000 }],
+            "CurrentEvents": [{ "event": 801, "start": 1700000000, "end": 1701000000 }],
+            "CurrentRaid": [{ "type": "Raid", "raid": 1, "terrain": "Outdoor", "start": 1700000000, "end": 1700500000 }]
+        },
+        {
+            "Name": "Global", "StudentMaxLevel": 85, "WeaponMaxLevel": 50, "BondMaxLevel": 50,
+            "EquipmentMaxLevel": [9, 9, 9], "CampaignMax": 22, "Events": [701],
+            "CurrentGacha": [
+                { "characters": [10000], "start": 1700000000, "end": 1700600000 },
+                { "characters": [10005], "start": 1700600000, "end": 1701200000 }
+            ],
+            "CurrentEvents": [{ "event": 701, "start": 1700000000, "end": 1700600000 }]
+        }
+    ]
+}"#;
+
+#[tokio::test]
+async fn banners_and_events() {
+    let source = MemorySource::new()
+        .with(Endpoint::Config, Language::English, CONFIG)
+        .with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let config = client.fetch_config().await.unwrap();
+    let students = client.fetch_all_students().await.unwrap();
+
+    let japan = config.region(Region::Japan).unwrap();
+    assert_eq!(japan.student_max_level, Some(90));
+    assert_eq!(japan.equipment_max_level, vec![10, 10, 10]);
+    assert_eq!(japan.current_raid[0].terrain(), Some(Terrain::Outdoor));
+    assert!(config.region(Region::China).is_none());
+    assert!(config.other.contains_key("Links"));
+
+    let at = Utc.timestamp_opt(1700100000, 0).unwrap();
+    let on_banner = config.students_on_banner(Region::Global, at, &students);
+    assert_eq!(on_banner.len(), 1);
+    assert_eq!(on_banner[0].id.to_u32(), 10000);
+
+    let later = Utc.timestamp_opt(1700700000, 0).unwrap();
+    let on_banner = config.students_on_banner(Region::Global, later, &students);
+    assert_eq!(on_banner.len(), 1);
+    assert_eq!(on_banner[0].id.to_u32(), 10005);
+    assert!(config.events_at(Region::Global, later).is_empty());
+    assert_eq!(config.events_at(Region::Japan, later)[0].event, 801);
+    assert!(japan.raids_at(later).is_empty());
+
+    let after = Utc.timestamp_opt(1800000000, 0).unwrap();
+    assert!(config
+        .students_on_banner(Region::Global, after, &students)
+        .is_empty());
+    assert!(config
+        .students_on_banner(Region::China, at, &students)
+        .is_empty());
+}
+
+#[test]
+fn daily_resets() {
+    let before = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        Region::Global.next_daily_reset(before),
+        Utc.with_ymd_and_hms(2024, 3, 1, 19, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Region::China.next_daily_reset(before),
+        Utc.with_ymd_and_hms(2024, 3, 1, 20, 0, 0).unwrap()
+    );
+
+    let at_reset = Utc.with_ymd_and_hms(2024, 3, 1, 19, 0, 0).unwrap();
+    assert_eq!(
+        Region::Japan.next_daily_reset(at_reset),
+        Utc.with_ymd_and_hms(2024, 3, 2, 19, 0, 0).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn config_is_not_localized() {
+    assert_eq!(Endpoint::Config.path(Language::Japanese), "config.json");
+    assert_eq!(
+        Endpoint::Students.path(Language::Japanese),
+        "jp/students.json"
+    );
+
+    let directory = tempfile::tempdir().unwrap();
+    std::fs::write(directory.path().join("config.json"), CONFIG).unwrap();
+    let client = BlueArchiveClient::builder()
+        .data_directory(directory.path())
+        .language(Language::Japanese)
+        .build();
+
+    let config = client.fetch_config().await.unwrap();
+    assert_eq!(config.regions.len(), 2);
+}
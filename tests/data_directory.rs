@@ -0,0 +1,48 @@
+use blue_archive::{BlueArchiveClient, BlueArchiveError, Language};
+
+const CURRENCIES: &str = r#"[
+    {
+        "Id": 1,
+        "Category": "Currency",
+        "Rarity": "R",
+        "Icon": "currency_icon_gold",
+        "Name": "Credits",
+        "Desc": "Currency used for many things."
+    }
+]"#;
+
+fn data_directory() -> tempfile::TempDir {
+    let directory = tempfile::tempdir().unwrap();
+    std::fs::create_dir(directory.path().join("en")).unwrap();
+    std::fs::write(directory.path().join("en/currency.json"), CURRENCIES).unwrap();
+    directory
+}
+
+#[tokio::test]
+async fn fetch_currencies_from_directory() {
+    let directory = data_directory();
+    let client = BlueArchiveClient::builder()
+        .data_directory(directory.path())
+        .build();
+
+    let currencies = client.fetch_all_currencies().await.unwrap();
+    assert_eq!(currencies.len(), 1);
+    assert_eq!(currencies[0].name, "Credits");
+}
+
+#[tokio::test]
+async fn fetch_missing_language_from_directory() {
+    let directory = data_directory();
+    let client = BlueArchiveClient::builder()
+        .data_directory(directory.path())
+        .language(Language::Japanese)
+        .build();
+
+    assert!(matches!(
+        client.fetch_all_currencies().await,
+        Err(BlueArchiveError::NotFound {
+            language: Language::Japanese,
+            ..
+        })
+    ));
+}
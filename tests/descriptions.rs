@@ -0,0 +1,151 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::{
+        description::{DescriptionRenderer, RenderMode},
+        summons::Skill as SummonSkill,
+        Localization,
+    },
+    BlueArchiveClient, Language,
+};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+const RAIDS: &str = include_str!("fixtures/raids.json");
+
+const SUMMONS: &str = r#"[
+    {
+        "Id": 99990, "Name": "Drone", "DevName": "Drone", "Type": "Summon", "TacticRole": null, "StarGrade": 1,
+        "BulletType": "Normal", "ArmorType": "LightArmor", "WeaponType": null, "StabilityPoint": 0,
+        "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 1, "MaxHP100": 2, "DefensePower1": 0, "DefensePower100": 0,
+        "HealPower1": 0, "HealPower100": 0, "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0,
+        "CriticalDamageRate": 0, "AmmoCount": 0, "AmmoCost": 0, "Range": 0, "MoveSpeed": 0, "RegenCost": 0,
+        "StreetBattleAdaptation": 2, "OutdoorBattleAdaptation": 2, "IndoorBattleAdaptation": 2,
+        "Skills": [
+            {
+                "SkillType": "normal", "Name": "Scan", "Desc": "Inflicts <d:DefensePower> by <?1> &amp; <c:Stunned>.",
+                "Parameters": [["10%", "20%"]], "Icon": "scan", "IsSummonSkill": true, "Effects": null
+            },
+            {
+                "SkillType": "passive", "Name": "Hover", "Desc": "Gains <s:Hover> for <?1>.",
+                "Parameters": [["1 sec", "2 sec"]], "Icon": "hover", "IsSummonSkill": true, "Effects": null
+            }
+        ]
+    }
+]"#;
+
+const LOCALIZATION: &str = r#"{
+    "BuffName": {
+        "Buff_AttackPower": "ATK Up",
+        "Debuff_DefensePower": "DEF Down",
+        "CC_Stunned": "Stun"
+    }
+}"#;
+
+fn client() -> BlueArchiveClient<MemorySource> {
+    let source = MemorySource::new()
+        .with(Endpoint::Students, Language::English, STUDENTS)
+        .with(Endpoint::Raids, Language::English, RAIDS)
+        .with(Endpoint::Summons, Language::English, SUMMONS)
+        .with(Endpoint::Localization, Language::English, LOCALIZATION);
+    BlueArchiveClient::builder().source(source).build()
+}
+
+#[tokio::test]
+async fn render_student_skills() {
+    let client = client();
+    let students = client.fetch_all_students().await.unwrap();
+    let localization = client.fetch_localization().await.unwrap();
+    let skills = &students[0].skills;
+
+    assert_eq!(
+        skills[0].render_description(0).as_deref(),
+        Some("Deals 532% damage to one enemy and 266% damage to enemies in a circular area around it.")
+    );
+    assert_eq!(
+        skills[0].render_description(4).as_deref(),
+        Some("Deals 931% damage to one enemy and 465% damage to enemies in a circular area around it.")
+    );
+    assert_eq!(skills[0].render_description(5), None);
+    assert_eq!(
+        skills[1].render_description(9).as_deref(),
+        Some("Increases AttackPower by 21%.")
+    );
+
+    let plain = DescriptionRenderer::new(RenderMode::Plain).localization(&localization);
+    assert_eq!(
+        skills[1].render_description_with(1, &plain).as_deref(),
+        Some("Increases ATK Up by 14.7%.")
+    );
+
+    let ansi = DescriptionRenderer::new(RenderMode::Ansi).localization(&localization);
+    assert_eq!(
+        skills[1].render_description_with(0, &ansi).as_deref(),
+        Some("Increases \x1b[34mATK Up\x1b[0m by \x1b[1m14%\x1b[0m.")
+    );
+
+    let html = DescriptionRenderer::new(RenderMode::Html).localization(&localization);
+    assert_eq!(
+        skills[1].render_description_with(0, &html).as_deref(),
+        Some("Increases <span class=\"skill-buff\">ATK Up</span> by <span class=\"skill-value\">14%</span>.")
+    );
+}
+
+#[test]
+fn reject_missing_parameters() {
+    let renderer = DescriptionRenderer::default();
+    let parameters = vec![vec!["10%".to_string(), "20%".to_string()]];
+
+    assert_eq!(
+        renderer.render("Heals <?1>.", &parameters, 1).as_deref(),
+        Some("Heals 20%.")
+    );
+    assert_eq!(renderer.render("Heals <?1>.", &parameters, 2), None);
+    assert_eq!(
+        renderer.render("Heals <?1> for <?2>.", &parameters, 0),
+        None
+    );
+    assert_eq!(renderer.render("Heals <?x>.", &parameters, 0), None);
+    assert_eq!(
+        renderer
+            .render("Heals <i:Unknown> & more.", &[], 0)
+            .as_deref(),
+        Some("Heals <i:Unknown> & more.")
+    );
+}
+
+#[tokio::test]
+async fn render_raid_and_summon_skills() {
+    let client = client();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+    let summons = client.fetch_all_summons().await.unwrap();
+    let localization = Localization::default();
+
+    let ex = &raid_data.raids[0].skills[1];
+    let html = DescriptionRenderer::new(RenderMode::Html).localization(&localization);
+    assert_eq!(
+        ex.render_description_with(1, &html).as_deref(),
+        Some("Deals <span class=\"skill-value\">200%</span> damage &amp; inflicts <span class=\"skill-buff\">Stunned</span> for <span class=\"skill-value\">2 sec</span>.")
+    );
+    assert_eq!(
+        ex.render_description(1).as_deref(),
+        Some("Deals 200% damage & inflicts Stunned for 2 sec.")
+    );
+    assert!(raid_data.raids[0].skills[0]
+        .render_description_with(0, &html)
+        .is_none());
+
+    let SummonSkill::Normal(scan) = &summons[0].skills[0] else {
+        panic!("expected a normal skill");
+    };
+    assert_eq!(
+        scan.render_description(1).as_deref(),
+        Some("Inflicts DefensePower by 20% & Stunned.")
+    );
+    let SummonSkill::Passive(hover) = &summons[0].skills[1] else {
+        panic!("expected a passive skill");
+    };
+    assert_eq!(
+        hover.render_description(0).as_deref(),
+        Some("Gains Hover for 1 sec.")
+    );
+    assert_eq!(hover.render_description(2), None);
+}
@@ -0,0 +1,94 @@
+use blue_archive::{
+    audit::{audit_endpoint, Finding},
+    source::{Endpoint, MemorySource},
+    types::{item::ItemIndex, stages::RewardType},
+    BlueArchiveClient, Language, Region,
+};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+const EVENTS: &str = r#"[
+    {
+        "Id": 801, "Name": "Summer Special Operations", "IsReleased": [true, true, false],
+        "EventCurrency": [80101, 80102],
+        "Shop": [
+            { "Id": 1, "Goods": { "Type": "Item", "Id": 5000, "Amount": 2 }, "CostId": 80101, "CostAmount": 50, "Stock": 10 },
+            { "Id": 2, "Goods": { "Type": "Equipment", "Id": 2000 }, "CostId": 80102, "CostAmount": 5 },
+            { "Id": 3, "Goods": { "Type": "Furniture", "Id": 30 }, "CostId": 80101, "CostAmount": 300, "Stock": 1 }
+        ],
+        "PointRewards": [
+            { "Points": 500, "Rewards": [{ "Type": "Currency", "Id": 3, "Amount": 20 }] },
+            { "Points": 1000, "Rewards": [{ "Type": "Title", "Id": 9 }] }
+        ],
+        "BonusStudents": [
+            { "CharacterId": 10000, "Currency": 80101, "Bonus": 1500 },
+            { "CharacterId": 10000, "Currency": 80102, "Bonus": 1000 },
+            { "CharacterId": 10005, "Currency": 80101, "Bonus": 2500 },
+            { "CharacterId": 10010, "Currency": 80102, "Bonus": 500 }
+        ]
+    },
+    { "Id": 802, "Name": "Rerun", "IsReleased": [true, false, false] }
+]"#;
+
+const ITEMS: &str = r#"[
+    { "Id": 80101, "IsReleased": [true, true, false], "Category": "Coin", "Rarity": "N", "Icon": "event_coin_1", "Name": "Sea Shell" },
+    { "Id": 5000, "IsReleased": [true, true, true], "Category": "Material", "Rarity": "SR", "Icon": "book_1", "Name": "Tech Note" }
+]"#;
+
+#[tokio::test]
+async fn event_bonus_students() {
+    let source = MemorySource::new()
+        .with(Endpoint::Events, Language::English, EVENTS)
+        .with(Endpoint::Items, Language::English, ITEMS)
+        .with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let events = client.fetch_all_events().await.unwrap();
+    let students = client.fetch_all_students().await.unwrap();
+    let items = ItemIndex::new(client.fetch_all_items().await.unwrap());
+
+    let event = &events[0];
+    assert!(event.is_released_in(Region::Global));
+    assert!(!event.is_released_in(Region::China));
+    assert_eq!(event.bonus_student_ids(), vec![10000, 10005, 10010]);
+    assert_eq!(event.bonus_students(&students).len(), 2);
+    assert_eq!(event.bonus_of(10000).len(), 2);
+    assert_eq!(event.bonus_of(10005)[0].percentage(), 25.0);
+    assert!(events[1].bonus_students(&students).is_empty());
+
+    let roster = event.roster_bonus(&[10000, 10005, 10005, 99999]);
+    assert_eq!(roster.len(), 2);
+    assert_eq!(roster[&80101], 4000);
+    assert_eq!(roster[&80102], 1000);
+    assert!(event.roster_bonus(&[99999]).is_empty());
+
+    let currencies = event.currency_items(&items);
+    assert_eq!(currencies.len(), 1);
+    assert_eq!(currencies[0].name, "Sea Shell");
+
+    let shop = event.shop_for(80101);
+    assert_eq!(shop.len(), 2);
+    assert_eq!(shop[0].goods.item(&items).unwrap().name, "Tech Note");
+    assert_eq!(shop[0].stock, Some(10));
+    assert_eq!(event.shop_for(80102)[0].goods.amount, 1);
+    assert_eq!(
+        event.shop_for(80102)[0].goods.reward_type(),
+        RewardType::Equipment
+    );
+    assert_eq!(event.point_rewards[0].points, 500);
+}
+
+#[test]
+fn audit_events() {
+    let audit = audit_endpoint(Endpoint::Events, Language::English, EVENTS.as_bytes()).unwrap();
+    let findings = audit
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.findings)
+        .collect::<Vec<_>>();
+    assert_eq!(audit.entities[0].id, Some(801));
+    assert!(matches!(
+        findings[..],
+        [Finding::UnknownValue { path, kind: "RewardType", value }]
+            if path == "[0].PointRewards[1].Rewards[0].Type" && value == "Title"
+    ));
+}
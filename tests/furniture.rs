@@ -0,0 +1,78 @@
+use blue_archive::{
+    audit::audit_endpoint,
+    source::{Endpoint, MemorySource},
+    types::{
+        furniture::{FurnitureCategory, FurnitureSubCategory},
+        Rarity,
+    },
+    BlueArchiveClient, Language,
+};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+const FURNITURE: &str = r#"[
+    {
+        "Id": 1, "IsReleased": [true, true, true], "Category": "Furnitures", "SubCategory": "Table",
+        "Rarity": "SR", "Icon": "Lobby_Table_1", "Name": "Office Desk", "Desc": "A sturdy &amp; plain desk.",
+        "Tags": ["BC"], "ComfortBonus": 100, "SetGroupId": 3, "Interaction": [[10000], [10000, 10005], []]
+    },
+    {
+        "Id": 2, "IsReleased": [true, false, false], "Category": "Decorations", "SubCategory": "WallDecoration",
+        "Rarity": "N", "Icon": "Lobby_Wall_2", "Name": "Office Clock", "ComfortBonus": 10, "SetGroupId": 3,
+        "Interaction": [10005]
+    },
+    {
+        "Id": 3, "IsReleased": [true, true, true], "Category": "Rooftops", "SubCategory": "Floor",
+        "Rarity": "R", "Icon": "Lobby_Floor_3", "Name": "Roof Tiles"
+    }
+]"#;
+
+#[tokio::test]
+async fn furniture_interactions() {
+    let source = MemorySource::new()
+        .with(Endpoint::Furniture, Language::English, FURNITURE)
+        .with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder()
+        .source(source)
+        .image_uri("https://example.com/images")
+        .build();
+    let furniture = client.fetch_all_furniture().await.unwrap();
+    let students = client.fetch_all_students().await.unwrap();
+
+    let desk = &furniture[0];
+    assert_eq!(desk.category(), FurnitureCategory::Furniture);
+    assert_eq!(desk.sub_category(), FurnitureSubCategory::Table);
+    assert_eq!(desk.rarity, Rarity::SR);
+    assert_eq!(desk.description, "A sturdy & plain desk.");
+    assert_eq!(
+        desk.icon_url(),
+        "https://example.com/images/furniture/Lobby_Table_1.webp"
+    );
+    assert_eq!(desk.interaction_student_ids().len(), 2);
+    assert_eq!(desk.interaction_students(&students).len(), 2);
+    assert_eq!(desk.set_pieces(&furniture).len(), 2);
+
+    let hina = students
+        .iter()
+        .find(|student| student.name == "Hina")
+        .unwrap();
+    assert_eq!(hina.interaction_furniture(&furniture).len(), 2);
+
+    let roof = &furniture[2];
+    assert_eq!(
+        roof.category(),
+        FurnitureCategory::Unknown("Rooftops".to_string())
+    );
+    assert_eq!(roof.sub_category(), FurnitureSubCategory::Floor);
+    assert!(!roof.has_interaction());
+    assert!(roof.set_pieces(&furniture).is_empty());
+}
+
+#[test]
+fn audit_unknown_furniture_category() {
+    let audit =
+        audit_endpoint(Endpoint::Furniture, Language::English, FURNITURE.as_bytes()).unwrap();
+    let findings = audit.entities.iter().flat_map(|entity| &entity.findings);
+    assert_eq!(findings.count(), 1);
+    assert_eq!(audit.entities[0].id, Some(3));
+}
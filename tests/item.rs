@@ -0,0 +1,79 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::{
+        item::{ItemCategory, ItemSubCategory},
+        Rarity,
+    },
+    BlueArchiveClient, Language,
+};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+const ITEMS: &str = r#"[
+    {
+        "Id": 2000, "IsReleased": [true, true, true], "Category": "Material", "SubCategory": "Artifact",
+        "Rarity": "N", "Icon": "item_icon_artifact_0", "Name": "Nebra Disk Fragment", "Desc": "An artifact.", "Tags": []
+    },
+    {
+        "Id": 4000, "IsReleased": [true, true, true], "Category": "Material", "SubCategory": "BookItem",
+        "Rarity": "N", "Icon": "item_icon_skillbook_0", "Name": "Beginner Tech Notes", "Desc": "A tech note."
+    },
+    {
+        "Id": 4040, "IsReleased": [true, true, true], "Category": "Material", "SubCategory": "CDItem",
+        "Rarity": "R", "Icon": "item_icon_exskill_0", "Name": "Beginner Tactical Training Blu-ray", "Desc": ""
+    },
+    {
+        "Id": 5000, "IsReleased": [true, false, false], "Category": "Sticker", "Rarity": "SSR",
+        "Icon": "item_icon_sticker", "Name": "Sticker"
+    }
+]"#;
+
+#[tokio::test]
+async fn resolve_item_ids() {
+    let source = MemorySource::new()
+        .with(Endpoint::Items, Language::English, ITEMS)
+        .with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder()
+        .source(source)
+        .image_uri("https://example.com/images")
+        .build();
+    let items = client.fetch_item_index().await.unwrap();
+    let students = client.fetch_all_students().await.unwrap();
+
+    assert_eq!(items.len(), 4);
+    let notes = items.get(4000u32).unwrap();
+    assert_eq!(notes.category(), ItemCategory::Material);
+    assert_eq!(notes.sub_category(), Some(ItemSubCategory::TechNote));
+    assert_eq!(notes.rarity, Rarity::N);
+    assert_eq!(
+        notes.icon_url(),
+        "https://example.com/images/item/item_icon_skillbook_0.webp"
+    );
+
+    let sticker = items.get(5000u32).unwrap();
+    assert_eq!(
+        sticker.category(),
+        ItemCategory::Unknown("Sticker".to_string())
+    );
+    assert_eq!(sticker.sub_category(), None);
+
+    let aru = &students[0];
+    let ex = items.skill_ex_materials(aru);
+    assert_eq!(ex.len(), 1);
+    assert_eq!(
+        ex[0][1].item.unwrap().sub_category(),
+        Some(ItemSubCategory::Bluray)
+    );
+    assert_eq!(ex[0][1].amount, 6);
+
+    let skill = items.skill_materials(aru);
+    assert_eq!(skill.len(), 2);
+    assert_eq!(skill[0][0].item.unwrap().name, "Nebra Disk Fragment");
+    assert_eq!(skill[1][0].id, 2001);
+    assert!(skill[1][0].item.is_none());
+
+    let gear = students[1].gear().unwrap();
+    let tiers = items.tier_up_materials(&gear);
+    assert_eq!(tiers[0][0].item.map(|item| item.id.to_u32()), Some(4000));
+    assert_eq!(tiers[0][0].amount, 2);
+}
@@ -0,0 +1,46 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::Effect,
+    BlueArchiveClient, Language, School,
+};
+
+const LOCALIZATION: &str = r#"{
+    "StatName": { "AttackPower": "ATK", "MaxHP": "Max HP" },
+    "BuffName": { "Buff_AttackPower": "ATK Up", "CC_Stunned": "Stun" },
+    "ui": { "student": "Students", "nested": { "ignored": true } },
+    "SchoolLong": { "RedWinter": "Red Winter Federal Academy" },
+    "ServerName": ["Japan", "Global", "China"]
+}"#;
+
+#[test]
+fn localization_file_name() {
+    assert_eq!(Endpoint::Localization.file_name(), "localization.json");
+}
+
+#[tokio::test]
+async fn translate_raw_keys() {
+    let source = MemorySource::new().with(Endpoint::Localization, Language::English, LOCALIZATION);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let localization = client.fetch_localization().await.unwrap();
+
+    assert_eq!(localization.stat_name("AttackPower_Coefficient"), "ATK");
+    assert_eq!(localization.stat_name("MaxHP_Base"), "Max HP");
+    assert_eq!(localization.stat_name("Unheard_Of"), "Unheard_Of");
+    assert_eq!(
+        localization.stat_buff_name("AttackPower_Coefficient", false),
+        "ATK Up"
+    );
+    assert_eq!(localization.ui("student"), Some("Students"));
+    assert_eq!(localization.ui("nested"), None);
+    assert_eq!(
+        localization.school_name(&School::RedWinter),
+        "Red Winter Federal Academy"
+    );
+    assert!(localization.other.contains_key("ServerName"));
+
+    let stun: Effect = serde_json::from_str(
+        r#"{ "Type": "CrowdControl", "Chance": "1000", "Icon": "CC_Stunned", "Scale": [1] }"#,
+    )
+    .unwrap();
+    assert_eq!(localization.effect_name(&stun).as_deref(), Some("Stun"));
+}
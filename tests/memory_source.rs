@@ -0,0 +1,64 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    BlueArchiveClient, BlueArchiveError, Language,
+};
+
+const ENEMIES: &str = r#"[]"#;
+
+const CURRENCIES: &str = r#"[
+    { "Id": 1, "Category": "Currency", "Rarity": "R", "Icon": "currency_icon_gold", "Name": "Credits", "Desc": "" },
+    { "Id": 2, "Category": "Currency", "Rarity": "R", "Icon": "currency_icon_gem", "Name": 2, "Desc": "" }
+]"#;
+
+#[tokio::test]
+async fn fetch_enemies_from_memory() {
+    let source = MemorySource::new().with(Endpoint::Enemies, Language::English, ENEMIES);
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    assert!(client.fetch_all_enemies().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn fetch_missing_endpoint_from_memory() {
+    let client = BlueArchiveClient::builder()
+        .source(MemorySource::new())
+        .build();
+
+    assert!(matches!(
+        client.fetch_all_enemies().await,
+        Err(BlueArchiveError::NotFound {
+            endpoint: Endpoint::Enemies,
+            language: Language::English
+        })
+    ));
+}
+
+#[tokio::test]
+async fn decode_error_has_path_and_id() {
+    let source = MemorySource::new().with(Endpoint::Currency, Language::English, CURRENCIES);
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    match client.fetch_all_currencies().await {
+        Err(BlueArchiveError::Decode {
+            endpoint, path, id, ..
+        }) => {
+            assert_eq!(endpoint, Endpoint::Currency);
+            assert_eq!(path, "[1].Name");
+            assert_eq!(id, Some(2));
+        }
+        result => panic!("expected a decode error, got {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn lenient_fetch_drops_malformed_elements() {
+    let source = MemorySource::new().with(Endpoint::Currency, Language::English, CURRENCIES);
+    let client = BlueArchiveClient::builder().source(source).build();
+
+    let decoded = client.fetch_all_currencies_lenient().await.unwrap();
+    assert_eq!(decoded.items.len(), 1);
+    assert_eq!(decoded.items[0].name, "Credits");
+    assert!(!decoded.is_complete());
+    assert_eq!(decoded.failed_ids(), vec![2]);
+    assert_eq!(decoded.failures[0].path, "[1].Name");
+}
@@ -0,0 +1,56 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::enemy::UnresolvedEnemy,
+    BlueArchiveClient, Language,
+};
+
+const RAIDS: &str = include_str!("fixtures/raids.json");
+
+fn enemy(id: u32, name: &str, max_hp: u32) -> String {
+    format!(
+        r#"{{
+            "Id": {id}, "DevName": "Binah_Raid", "Name": "{name}",
+            "SquadType": "Main", "Rank": "Boss", "BulletType": "Pierce", "ArmorType": "HeavyArmor",
+            "WeaponType": "None", "Size": "XLarge", "Icon": null, "StabilityPoint": 0, "StabilityRate": 0,
+            "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 3, "MaxHP100": {max_hp},
+            "DefensePower1": 5, "DefensePower100": 6, "HealPower1": 7, "HealPower100": 8,
+            "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0, "CriticalDamageRate": 0,
+            "CriticalResistPoint": 0, "CriticalDamageResistRate": 0, "Range": 0, "DamagedRatio": 0
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn resolve_raid_enemies_per_difficulty() {
+    let enemies = format!(
+        "[{}, {}, {}]",
+        enemy(7010101, "Binah", 1_000_000),
+        enemy(7010102, "Binah", 2_000_000),
+        enemy(7030102, "Hieronymus", 3_000_000),
+    );
+    let source = MemorySource::new()
+        .with(Endpoint::Raids, Language::English, RAIDS)
+        .with(Endpoint::Enemies, Language::English, enemies);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+    let enemies = client.fetch_all_enemies().await.unwrap();
+
+    let binah = raid_data.raids[0].resolve_enemies(&enemies);
+    assert_eq!(binah.tiers.len(), 7);
+    assert_eq!(binah.tier(1)[0].max_hp_100, 2_000_000);
+    assert!(binah.tier(2).is_empty());
+    assert!(binah.tier(10).is_empty());
+    assert!(!binah.is_complete());
+    assert_eq!(binah.unresolved.len(), 5);
+    assert_eq!(
+        binah.unresolved[0],
+        UnresolvedEnemy {
+            difficulty: 2,
+            id: 7010103
+        }
+    );
+
+    let hieronymus = raid_data.world_raid[0].resolve_enemies(&enemies);
+    assert_eq!(hieronymus.tier(1)[0].name, "Hieronymus");
+    assert_eq!(hieronymus.unresolved[0].id, 7030101);
+}
@@ -0,0 +1,75 @@
+use blue_archive::{
+    audit::{audit_endpoint, Finding},
+    source::{Endpoint, MemorySource},
+    types::raids::RaidDifficulty,
+    BlueArchiveClient, Language, Region, Terrain,
+};
+use chrono::{TimeZone, Utc};
+
+const RAIDS: &str = include_str!("fixtures/raids.json");
+
+#[tokio::test]
+async fn current_and_next_seasons() {
+    let source = MemorySource::new().with(Endpoint::Raids, Language::English, RAIDS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+
+    let during_first = Utc.timestamp_opt(1612400000, 0).unwrap();
+    let current = raid_data
+        .current_season(Region::Japan, during_first)
+        .unwrap();
+    assert_eq!(current.season, 1);
+    assert_eq!(current.terrain(), Terrain::Outdoor);
+    assert_eq!(
+        current.opened_difficulties(),
+        [
+            RaidDifficulty::Normal,
+            RaidDifficulty::Hard,
+            RaidDifficulty::VeryHard,
+            RaidDifficulty::Hardcore
+        ]
+    );
+    assert_eq!(raid_data.raid_of(current).unwrap().name, "Binah");
+
+    let next = raid_data.next_season(Region::Japan, during_first).unwrap();
+    assert_eq!(next.season, 2);
+    assert_eq!(
+        next.opened_difficulties()[3],
+        RaidDifficulty::Unknown("Apocalypse".to_string())
+    );
+    assert_eq!(RaidDifficulty::Lunatic.index(), Some(7));
+
+    let between = Utc.timestamp_opt(1613000000, 0).unwrap();
+    assert!(raid_data.current_season(Region::Japan, between).is_none());
+    assert_eq!(
+        raid_data
+            .next_season(Region::Global, between)
+            .unwrap()
+            .raid_id,
+        2
+    );
+    assert!(raid_data.next_season(Region::China, between).is_none());
+
+    let eliminate = &raid_data.seasons(Region::Japan).unwrap().eliminate_seasons[0];
+    assert_eq!(eliminate.armor(), Some(blue_archive::Armor::HeavyArmor));
+}
+
+#[test]
+fn audit_unknown_season_values() {
+    let audit = audit_endpoint(Endpoint::Raids, Language::English, RAIDS.as_bytes()).unwrap();
+    let findings = audit
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.findings)
+        .collect::<Vec<_>>();
+    assert!(findings.contains(&&Finding::UnknownValue {
+        path: "RaidSeasons[0].Seasons[1].OpenDifficulty[3]".to_string(),
+        kind: "RaidDifficulty",
+        value: "Apocalypse".to_string()
+    }));
+    assert!(findings.contains(&&Finding::UnknownValue {
+        path: "RaidSeasons[0].EliminateSeasons[0].Terrain".to_string(),
+        kind: "Terrain",
+        value: "Underwater".to_string()
+    }));
+}
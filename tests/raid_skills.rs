@@ -0,0 +1,50 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::raids::Skill,
+    BlueArchiveClient, Language,
+};
+
+const RAIDS: &str = include_str!("fixtures/raids.json");
+
+#[tokio::test]
+async fn raid_skills_by_difficulty() {
+    let source = MemorySource::new().with(Endpoint::Raids, Language::English, RAIDS);
+    let client = BlueArchiveClient::builder()
+        .source(source)
+        .image_uri("https://example.com/images")
+        .build();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+    let binah = &raid_data.raids[0];
+
+    assert_eq!(binah.skills_at(0).len(), 2);
+    assert_eq!(binah.skills_at(4).len(), 3);
+    assert_eq!(binah.skills_at(6).len(), 4);
+
+    let ex = &binah.skills[1];
+    assert_eq!(ex.id(), "Binah_Ex1");
+    assert_eq!(ex.name(), Some("Sacred Scales"));
+    assert_eq!(ex.atg_cost(), 4);
+    assert_eq!(ex.effects().len(), 1);
+    assert_eq!(
+        ex.render_description(1).as_deref(),
+        Some("Deals 200% damage & inflicts Stunned for 2 sec.")
+    );
+    assert_eq!(
+        ex.icon_url().as_deref(),
+        Some("https://example.com/images/raid/skill/Binah_Ex1.webp")
+    );
+    assert!(matches!(ex, Skill::EX(skill) if skill.parameters().len() == 2));
+
+    let auto = &binah.skills[0];
+    assert!(auto.name().is_none());
+    assert!(auto.icon_url().is_none());
+    assert!(auto.is_active_at(0));
+
+    let normal = &binah.skills[2];
+    assert_eq!(normal.min_difficulty(), Some(4));
+    assert!(!normal.is_active_at(3));
+    assert_eq!(
+        normal.render_description(0).as_deref(),
+        Some("Removes debuffs.")
+    );
+}
@@ -0,0 +1,158 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use blue_archive::{api::StatusCode, source::RetryPolicy, BlueArchiveClient, BlueArchiveError};
+
+/// Starts a stand-in for the data server, which answers each request with the next of the given responses.
+///
+/// Returns the data root to use, and the amount of requests that were served.
+fn serve(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let data_uri = format!("http://{}/data", listener.local_addr().unwrap());
+    let served = Arc::new(AtomicUsize::new(0));
+
+    let counter = served.clone();
+    thread::spawn(move || {
+        for (stream, response) in listener.incoming().zip(responses) {
+            let mut stream = stream.unwrap();
+            BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty())
+                .for_each(drop);
+            stream.write_all(response.as_bytes()).unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    (data_uri, served)
+}
+
+const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]";
+const UNAVAILABLE: &str =
+    "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+const RATE_LIMITED: &str =
+    "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+/// Promises a longer body than it sends before the socket is closed, as if the connection was reset while downloading.
+const TRUNCATED: &str =
+    "HTTP/1.1 200 OK\r\nContent-Length: 64\r\nConnection: close\r\n\r\n[{\"Id\": 1";
+const NOT_FOUND: &str = "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+fn quick_policy() -> RetryPolicy {
+    RetryPolicy::new().with_backoff(Duration::from_millis(10), Duration::from_millis(50))
+}
+
+#[tokio::test]
+async fn retry_transient_status() {
+    let (data_uri, served) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy())
+        .build();
+
+    assert!(client.fetch_all_currencies().await.unwrap().is_empty());
+    assert_eq!(served.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn give_up_after_max_attempts() {
+    let (data_uri, served) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy().with_max_attempts(2))
+        .build();
+
+    assert!(matches!(
+        client.fetch_all_currencies().await,
+        Err(BlueArchiveError::Status {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        })
+    ));
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn skip_status_that_is_not_retryable() {
+    let (data_uri, served) = serve(vec![NOT_FOUND, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy())
+        .build();
+
+    assert!(client.fetch_all_currencies().await.is_err());
+    assert_eq!(served.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn retry_truncated_body() {
+    let (data_uri, served) = serve(vec![TRUNCATED, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy())
+        .build();
+
+    assert!(client.fetch_all_currencies().await.unwrap().is_empty());
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn give_up_on_truncated_body() {
+    let (data_uri, served) = serve(vec![TRUNCATED, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    let error = client.fetch_all_currencies().await.unwrap_err();
+    assert!(matches!(error, BlueArchiveError::Transport { .. }));
+    assert_eq!(served.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn honour_retry_after() {
+    let (data_uri, served) = serve(vec![RATE_LIMITED, OK]);
+    let client = BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy().with_backoff(Duration::ZERO, Duration::from_secs(5)))
+        .build();
+
+    let started = Instant::now();
+    client.fetch_all_currencies().await.unwrap();
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retry_transient_status_blocking() {
+    let (data_uri, served) = serve(vec![RATE_LIMITED, OK]);
+    let client = blue_archive::blocking::BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy().with_retry_after(false))
+        .build();
+
+    assert!(client.get_all_currencies().unwrap().is_empty());
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retry_truncated_body_blocking() {
+    let (data_uri, served) = serve(vec![TRUNCATED, OK]);
+    let client = blue_archive::blocking::BlueArchiveClient::builder()
+        .data_uri(data_uri)
+        .retry_policy(quick_policy())
+        .build();
+
+    assert!(client.get_all_currencies().unwrap().is_empty());
+    assert_eq!(served.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,188 @@
+use blue_archive::{
+    audit::{audit_endpoint, Finding},
+    source::{Endpoint, MemorySource},
+    types::{
+        equipment::EquipmentCategory,
+        stages::{EntryCost, RewardType, StageKind},
+    },
+    BlueArchiveClient, Language, Terrain,
+};
+
+const STAGES: &str = r#"{
+    "Campaign": [
+        {
+            "Id": 1011101, "Area": 1, "Stage": 1, "Difficulty": 0, "Name": "1-1", "Level": 1,
+            "EntryCost": [[5, 10]], "Terrain": "Street",
+            "Formations": [{ "Id": 1, "EnemyList": [101, 102] }, { "Id": 2, "EnemyList": [103] }],
+            "Rewards": {
+                "Default": [
+                    { "Type": "Equipment", "Id": 2000, "Chance": 0.3, "Amount": 1 },
+                    { "Type": "Currency", "Id": 1, "Amount": 2000 }
+                ],
+                "FirstClear": [{ "Type": "Currency", "Id": 3, "Amount": 20 }]
+            }
+        },
+        {
+            "Id": 1011201, "Area": 1, "Stage": 1, "Difficulty": 1, "Name": "1-1H", "Level": 3,
+            "EntryCost": [[5, 20]], "Terrain": "Outdoor",
+            "Rewards": { "Default": [[2000, 0.6], [2001, 0.4, 2]] }
+        }
+    ],
+    "WeekDungeon": [
+        { "Id": 3010101, "Type": "ChaserA", "Stage": 1, "EntryCost": [[8, 1]], "Terrain": "Street", "Rewards": { "Default": [] } },
+        { "Id": 3020101, "Type": "FindGift", "Stage": 1, "EntryCost": [[8, 1]], "Terrain": "Swamp" }
+    ],
+    "SchoolDungeon": [
+        { "Id": 4010101, "Stage": 1, "EntryCost": [[5, 10]], "Terrain": "Indoor",
+          "Rewards": { "Default": [{ "Type": "Gem", "Id": 1, "Chance": 0.1 }] } }
+    ],
+    "Event": [
+        { "Id": 8010101, "EventId": 801, "Stage": 1, "Name": "Story 1", "EntryCost": [[5, 10]] },
+        { "Id": 7010101, "EventId": 701, "Stage": 1, "Name": "Quest 1", "EntryCost": [[5, 15]] }
+    ]
+}"#;
+
+fn equipment(id: u32, category: &str, tier: u8) -> String {
+    format!(
+        r#"{{
+            "Id": {id}, "Name": "T{tier} {category}", "IsReleased": [true, true, true], "Desc": "",
+            "Category": "{category}", "Rarity": "N", "Tier": {tier}, "Icon": "equipment_{id}",
+            "Shops": [], "StatType": [], "StatValue": [], "Recipe": null, "RecipeCost": null
+        }}"#
+    )
+}
+
+fn enemy(id: u32, name: &str) -> String {
+    format!(
+        r#"{{
+            "Id": {id}, "DevName": "{name}", "Name": "{name}",
+            "SquadType": "Main", "Rank": "Minion", "BulletType": "Explosion", "ArmorType": "LightArmor",
+            "WeaponType": "SMG", "Size": "Medium", "Icon": null, "StabilityPoint": 0, "StabilityRate": 0,
+            "AttackPower1": 1, "AttackPower100": 2, "MaxHP1": 3, "MaxHP100": 4,
+            "DefensePower1": 5, "DefensePower100": 6, "HealPower1": 7, "HealPower100": 8,
+            "DodgePoint": 0, "AccuracyPoint": 0, "CriticalPoint": 0, "CriticalDamageRate": 0,
+            "CriticalResistPoint": 0, "CriticalDamageResistRate": 0, "Range": 0, "DamagedRatio": 0
+        }}"#
+    )
+}
+
+fn client() -> BlueArchiveClient<MemorySource> {
+    let equipment = format!(
+        "[{}, {}]",
+        equipment(2000, "Hat", 2),
+        equipment(2001, "Gloves", 2)
+    );
+    let enemies = format!("[{}, {}]", enemy(101, "Grunt"), enemy(102, "Sniper"));
+    let source = MemorySource::new()
+        .with(Endpoint::Stages, Language::English, STAGES)
+        .with(Endpoint::Equipment, Language::English, equipment)
+        .with(Endpoint::Enemies, Language::English, enemies);
+    BlueArchiveClient::builder().source(source).build()
+}
+
+#[tokio::test]
+async fn stage_kinds_and_costs() {
+    let stages = client().fetch_stage_data().await.unwrap();
+
+    assert_eq!(stages.stages(StageKind::Campaign).len(), 1);
+    assert_eq!(stages.stages(StageKind::Hard)[0].name, "1-1H");
+    assert_eq!(stages.stages(StageKind::Bounty)[0].id, 3010101);
+    assert_eq!(stages.stages(StageKind::Commission)[0].id, 3020101);
+    assert_eq!(stages.stages(StageKind::Scrimmage).len(), 1);
+    assert_eq!(stages.event_stages(801)[0].name, "Story 1");
+
+    let stage = stages.get(1011101).unwrap();
+    assert_eq!(
+        stage.entry_cost,
+        vec![EntryCost {
+            currency_id: 5,
+            amount: 10
+        }]
+    );
+    assert_eq!(stage.terrain(), Some(Terrain::Street));
+    assert_eq!(stages.event_stages(801)[0].terrain(), None);
+
+    let credits = &stage.rewards.default[1];
+    assert_eq!(credits.reward_type(), Some(RewardType::Currency));
+    assert_eq!(credits.chance, 1.0);
+    assert_eq!(credits.expected_amount(), 2000.0);
+    assert_eq!(stage.rewards.first_clear[0].amount, 20);
+}
+
+#[tokio::test]
+async fn stage_enemies_and_drops() {
+    let client = client();
+    let stages = client.fetch_stage_data().await.unwrap();
+    let equipment = client.fetch_all_equipment().await.unwrap();
+    let enemies = client.fetch_all_enemies().await.unwrap();
+
+    let formations = stages.get(1011101).unwrap().resolve_enemies(&enemies);
+    assert_eq!(formations.tier(0).len(), 2);
+    assert!(formations.tier(1).is_empty());
+    assert_eq!(formations.unresolved[0].id, 103);
+
+    let hats = stages.stages_dropping(&EquipmentCategory::Hat, &equipment);
+    assert_eq!(hats.len(), 2);
+    assert_eq!(hats[0].stage.id, 1011201);
+    assert_eq!(hats[0].kind, StageKind::Hard);
+    assert_eq!(hats[0].reward.reward_type(), None);
+    assert_eq!(hats[1].reward.chance, 0.3);
+
+    let gloves = stages.stages_dropping(&EquipmentCategory::Gloves, &equipment);
+    assert_eq!(gloves.len(), 1);
+    assert_eq!(gloves[0].reward.amount, 2);
+}
+
+#[tokio::test]
+async fn reject_malformed_rewards() {
+    let client = |rewards: &str| {
+        let stages = format!(
+            r#"{{ "Campaign": [{{ "Id": 1, "Stage": 1, "Rewards": {{ "Default": [{rewards}] }} }}] }}"#
+        );
+        let source = MemorySource::new().with(Endpoint::Stages, Language::English, stages);
+        BlueArchiveClient::builder().source(source).build()
+    };
+
+    assert!(client("[2000, 0.3, 2]").fetch_stage_data().await.is_ok());
+    for rewards in [
+        "[]",
+        "[2000]",
+        "[2000.5, 0.3]",
+        "[-1, 0.3]",
+        "[2000, 0.3, 1.5]",
+        "[2000, -0.1]",
+        "[2000, 1.5, 2]",
+    ] {
+        assert!(
+            client(rewards).fetch_stage_data().await.is_err(),
+            "{rewards} was accepted"
+        );
+    }
+}
+
+#[test]
+fn audit_stages() {
+    let audit = audit_endpoint(Endpoint::Stages, Language::English, STAGES.as_bytes()).unwrap();
+    let unknown = audit
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.findings)
+        .filter_map(|finding| match finding {
+            Finding::UnknownValue { path, kind, value } => {
+                Some((path.as_str(), *kind, value.as_str()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unknown,
+        vec![
+            ("WeekDungeon[1].Terrain", "Terrain", "Swamp"),
+            (
+                "SchoolDungeon[0].Rewards.Default[0].Type",
+                "RewardType",
+                "Gem"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,182 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::students::{student::LevelUpType, StudentStats},
+    BlueArchiveClient, Language,
+};
+
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+async fn students() -> Vec<blue_archive::types::Student> {
+    let source = MemorySource::new().with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    client.fetch_all_students().await.unwrap()
+}
+
+#[tokio::test]
+async fn stats_at_level_and_star_grade() {
+    let students = students().await;
+    let aru = &students[0];
+
+    assert_eq!(
+        aru.stats().calculate(),
+        StudentStats {
+            attack_power: 467,
+            max_hp: 2079,
+            defense_power: 19,
+            heal_power: 1543,
+        }
+    );
+
+    let calculator = aru.stats().level(85).star_grade(5).weapon_level(30);
+    assert_eq!(
+        calculator.base(),
+        StudentStats {
+            attack_power: 5060,
+            max_hp: 20226,
+            defense_power: 104,
+            heal_power: 5118,
+        }
+    );
+    assert_eq!(calculator.weapon().attack_power, 240);
+    assert_eq!(calculator.calculate().attack_power, 5300);
+    assert_eq!(calculator.calculate().max_hp, 20226);
+
+    assert_eq!(
+        aru.stats().level(90).star_grade(5).base().attack_power,
+        5326
+    );
+    assert!(
+        aru.stats().level(100).base().attack_power < aru.stats().level(110).base().attack_power
+    );
+
+    let far = aru.stats().level(u32::MAX).star_grade(5).bond_level(50);
+    assert_eq!(far.base().max_hp, u32::MAX);
+    assert_eq!(far.calculate().max_hp, u32::MAX);
+}
+
+#[tokio::test]
+async fn weapon_stats_follow_their_curve() {
+    let students = students().await;
+    let hina = &students[1];
+
+    assert_eq!(hina.weapon.stat_level_up_type, LevelUpType::Premature);
+    assert_eq!(
+        hina.stats()
+            .star_grade(5)
+            .weapon_level(30)
+            .weapon()
+            .attack_power,
+        343
+    );
+    assert_eq!(
+        hina.stats().star_grade(4).weapon_level(30).weapon(),
+        StudentStats::default()
+    );
+
+    assert_eq!(LevelUpType::Standard.scale(1), 0.0);
+    assert_eq!(LevelUpType::Standard.scale(100), 1.0);
+    assert_eq!(LevelUpType::Standard.scale(30), 0.2929);
+    assert!(LevelUpType::Premature.scale(30) > LevelUpType::Standard.scale(30));
+    assert!(LevelUpType::LateBloom.scale(30) < LevelUpType::Standard.scale(30));
+    assert_eq!(LevelUpType::LateBloom.scale(100), 1.0);
+}
+
+fn equipment(id: u32, category: &str, stats: &str) -> String {
+    format!(
+        r#"{{
+            "Id": {id}, "Name": "T7 {category}", "IsReleased": [true, true, true], "Desc": "",
+            "Category": "{category}", "Rarity": "SR", "Tier": 7, "Icon": "equipment_{id}",
+            "Shops": [], {stats}, "Recipe": null, "RecipeCost": null
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn stat_breakdown_by_source() {
+    let equipment = format!(
+        "[{}, {}, {}, {}]",
+        equipment(
+            1,
+            "Hat",
+            r#""StatType": ["AttackPower_Coefficient"], "StatValue": [[100, 1000]]"#
+        ),
+        equipment(
+            2,
+            "Hairpin",
+            r#""StatType": ["MaxHP_Base"], "StatValue": [[100, 2000]]"#
+        ),
+        equipment(
+            3,
+            "Watch",
+            r#""StatType": ["CriticalPoint_Base", "MaxHP_Base"], "StatValue": [[10, 100], [50, 500]]"#
+        ),
+        equipment(
+            4,
+            "Gloves",
+            r#""StatType": ["AttackPower_Coefficient"], "StatValue": [[100, 3000]]"#
+        ),
+    );
+    let source = MemorySource::new()
+        .with(Endpoint::Students, Language::English, STUDENTS)
+        .with(Endpoint::Equipment, Language::English, equipment);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let students = client.fetch_all_students().await.unwrap();
+    let equipment = client.fetch_all_equipment().await.unwrap();
+    let aru = &students[0];
+
+    let block = aru
+        .stats()
+        .level(85)
+        .star_grade(5)
+        .weapon_level(30)
+        .equipment_tiers(&[7, 7, 7], &equipment)
+        .equip(&equipment[3])
+        .bond_level(20)
+        .breakdown();
+
+    assert_eq!(block.base.attack_power, 5060);
+    assert_eq!(block.weapon.attack_power, 240);
+    assert_eq!(block.equipment.coefficient.attack_power, 1000);
+    assert_eq!(block.equipment.flat.max_hp, 2500);
+    assert_eq!(
+        block.bond,
+        StudentStats {
+            attack_power: 125,
+            ..Default::default()
+        }
+    );
+    assert_eq!(block.gear, Default::default());
+    assert_eq!(block.total.attack_power, 5968);
+    assert_eq!(block.total.max_hp, 22726);
+    assert_eq!(block.total.defense_power, 104);
+}
+
+#[tokio::test]
+async fn alternate_bonds_and_gear() {
+    let students = students().await;
+    let mut aru = students[0].clone();
+    let hina = &students[1];
+
+    assert_eq!(
+        aru.stats().alt_bond_level(hina, 20).bond(),
+        Default::default()
+    );
+
+    aru.favor_alts.push(10005);
+    assert_eq!(
+        aru.stats().bond_level(20).alt_bond_level(hina, 20).bond(),
+        StudentStats {
+            attack_power: 240,
+            defense_power: 15,
+            ..Default::default()
+        }
+    );
+
+    assert_eq!(hina.stats().gear().flat.attack_power, 0);
+    let with_gear = hina.stats().gear_tier(1);
+    assert_eq!(with_gear.gear().flat.attack_power, 123);
+    assert_eq!(
+        with_gear.calculate().attack_power,
+        hina.stats().calculate().attack_power + 123
+    );
+}
@@ -0,0 +1,57 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::raids::DungeonType,
+    Armor, BlueArchiveClient, Language, Terrain,
+};
+
+const RAIDS: &str = include_str!("fixtures/raids.json");
+const STUDENTS: &str = include_str!("fixtures/students.json");
+
+#[tokio::test]
+async fn drills_and_eligible_students() {
+    let source = MemorySource::new()
+        .with(Endpoint::Raids, Language::English, RAIDS)
+        .with(Endpoint::Students, Language::English, STUDENTS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+    let students = client.fetch_all_students().await.unwrap();
+
+    let shooting = &raid_data.time_attack[0];
+    assert_eq!(shooting.dungeon_type(), DungeonType::Shooting);
+    assert_eq!(shooting.terrain(), Terrain::Street);
+    assert_eq!(shooting.armor(), Armor::LightArmor);
+    assert_eq!(shooting.formations[0].enemy_list, [8010101, 8010102]);
+
+    let eligible = shooting.eligible_students(&students);
+    assert_eq!(eligible.len(), 1);
+    assert_eq!(eligible[0].name, "Aru");
+
+    let escort = &raid_data.time_attack[1];
+    assert_eq!(
+        escort.dungeon_type(),
+        DungeonType::Unknown("Escort".to_string())
+    );
+    assert_eq!(escort.eligible_students(&students)[0].name, "Hina");
+
+    let mut unknown_operand = shooting.clone();
+    unknown_operand.restrictions[0].operand = "GreaterThan".to_string();
+    assert!(unknown_operand.eligible_students(&students).is_empty());
+
+    assert!(raid_data.rules_of(shooting, 0).is_empty());
+    assert_eq!(shooting.rule_ids(2), [1, 2]);
+    let rules = raid_data.rules_of(shooting, 2);
+    assert_eq!(rules.len(), 2);
+    assert_eq!(
+        rules[0].description_at(1).as_deref(),
+        Some("Reduces healing by 20% & more.")
+    );
+    assert_eq!(rules[0].description_at(5), None);
+    assert_eq!(
+        rules[1].description_at(0).as_deref(),
+        Some("Enemies move 10% faster for 30 sec.")
+    );
+
+    let mut without_parameters = rules[1].clone();
+    without_parameters.parameters.truncate(1);
+    assert_eq!(without_parameters.description_at(0), None);
+}
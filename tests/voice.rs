@@ -0,0 +1,59 @@
+use blue_archive::{
+    source::{Endpoint, MemorySource},
+    types::{voice::VoiceCategory, ID},
+    BlueArchiveClient, Language,
+};
+
+const VOICE: &str = r#"{
+    "10000": {
+        "Normal": [
+            { "Group": "Formation_Select", "AudioClip": "JP_Aru/Aru_Formation_Select", "Transcription": "Let&#39;s go!" }
+        ],
+        "Lobby": [
+            { "Group": "Lobby", "AudioClip": ["JP_Aru/Aru_Lobby_1", "JP_Aru/Aru_Lobby_2"] }
+        ],
+        "Battle": [
+            { "Group": "ExSkill", "AudioClip": "JP_Aru/Aru_ExSkill_1", "Transcription": "" }
+        ]
+    },
+    "10001": { "Normal": [] }
+}"#;
+
+#[test]
+fn voice_file_name() {
+    assert_eq!(Endpoint::Voice.file_name(), "voice.json");
+}
+
+#[tokio::test]
+async fn voice_lines_by_student() {
+    let source = MemorySource::new().with(Endpoint::Voice, Language::English, VOICE);
+    let client = BlueArchiveClient::builder()
+        .source(source)
+        .voice_uri("https://example.com/voice/")
+        .build();
+    let voice_data = client.fetch_all_voice_lines().await.unwrap();
+
+    assert_eq!(voice_data.students.len(), 2);
+    let aru = voice_data
+        .students
+        .iter()
+        .find(|(id, _)| id.to_u32() == 10000)
+        .map(|(_, voice)| voice)
+        .unwrap();
+
+    let select = &aru.category(VoiceCategory::Normal)[0];
+    assert_eq!(select.group, "Formation_Select");
+    assert_eq!(select.transcription().as_deref(), Some("Let's go!"));
+    assert_eq!(
+        select.audio_urls(),
+        ["https://example.com/voice/JP_Aru/Aru_Formation_Select.ogg"]
+    );
+
+    assert_eq!(aru.lobby[0].audio_urls().len(), 2);
+    assert_eq!(aru.battle[0].transcription(), None);
+    assert!(aru.event.is_empty());
+    assert_eq!(aru.lines().count(), 3);
+
+    let empty = voice_data.students.keys().find(|id| id.to_u32() == 10001);
+    assert!(empty.is_some_and(|id: &ID| voice_data.get(id).is_some()));
+}
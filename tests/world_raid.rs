@@ -0,0 +1,60 @@
+use blue_archive::{
+    audit::{audit_endpoint, Finding},
+    source::{Endpoint, MemorySource},
+    Armor, BlueArchiveClient, BulletType, Language, Region, Terrain,
+};
+
+const RAIDS: &str = include_str!("fixtures/raids.json");
+
+#[tokio::test]
+async fn world_raid_tables_and_phases() {
+    let source = MemorySource::new().with(Endpoint::Raids, Language::English, RAIDS);
+    let client = BlueArchiveClient::builder().source(source).build();
+    let raid_data = client.fetch_raid_data().await.unwrap();
+
+    let hieronymus = &raid_data.world_raid[0];
+    assert_eq!(hieronymus.difficulty_count(), 2);
+    assert_eq!(hieronymus.hp(1), Some(5000000000));
+    assert_eq!(hieronymus.level(1), Some(70));
+    assert_eq!(hieronymus.difficulty_max(0), Some(3));
+    assert_eq!(hieronymus.regions(), [Region::Japan]);
+    assert!(!hieronymus.has_phases());
+    let phases = hieronymus.phases();
+    assert_eq!(phases.len(), 1);
+    assert_eq!(phases[0].armor(), Armor::LightArmor);
+    assert_eq!(phases[0].bullet_type(), BulletType::Mystic);
+
+    let kaiten = &raid_data.world_raid[1];
+    assert_eq!(kaiten.hp_table(), [1000000, 5000000, 20000000]);
+    assert_eq!(kaiten.hp(2), Some(20000000));
+    assert_eq!(kaiten.hp(3), None);
+    assert_eq!(kaiten.level_table(), [30, 50, 70]);
+    assert_eq!(kaiten.regions(), [Region::Japan, Region::Global]);
+    assert_eq!(
+        kaiten.terrains(),
+        [Terrain::Street, Terrain::Unknown("Sky".to_string())]
+    );
+    let phases = kaiten.phases();
+    assert_eq!(phases[1].bullet_type(), BulletType::Piercing);
+    assert_eq!(phases[1].enemy_list[0], [7040201]);
+}
+
+#[test]
+fn audit_world_raid_phases() {
+    let audit = audit_endpoint(Endpoint::Raids, Language::English, RAIDS.as_bytes()).unwrap();
+    let findings = audit
+        .entities
+        .iter()
+        .flat_map(|entity| &entity.findings)
+        .collect::<Vec<_>>();
+    assert!(findings.contains(&&Finding::UnknownValue {
+        path: "WorldRaid[1].Phases[1].ArmorType".to_string(),
+        kind: "Armor",
+        value: "Cardboard".to_string()
+    }));
+    assert!(findings.contains(&&Finding::UnknownValue {
+        path: "WorldRaid[1].Terrain[1]".to_string(),
+        kind: "Terrain",
+        value: "Sky".to_string()
+    }));
+}